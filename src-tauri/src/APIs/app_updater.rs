@@ -1,6 +1,10 @@
 use tauri_plugin_updater::UpdaterExt;
 use serde::{Serialize, Deserialize};
 use tauri_plugin_opener::OpenerExt;
+use tauri_plugin_updater::Updater;
+use crate::network::{is_offline, proxy_url, root_certificates};
+use crate::settings::load_settings;
+use std::time::Duration;
 
 #[derive(Serialize, Deserialize)]
 pub struct UpdateInfo {
@@ -29,7 +33,11 @@ pub fn get_platform_info() -> PlatformInfo {
 
 #[tauri::command]
 pub async fn check_for_updates(app: tauri::AppHandle) -> Result<Option<UpdateInfo>, String> {
-    let updater = app.updater_builder().build().map_err(|e| e.to_string())?;
+    if is_offline() {
+        return Ok(None);
+    }
+
+    let updater = build_updater(&app)?;
 
     match updater.check().await {
         Ok(Some(update)) => {
@@ -59,10 +67,41 @@ pub async fn open_release_page(app: tauri::AppHandle, version: String) -> Result
     Ok(())
 }
 
+fn build_updater(app: &tauri::AppHandle) -> Result<Updater, String> {
+    let settings = load_settings()?;
+    let mut builder = app.updater_builder();
+
+    if let Some(proxy) = proxy_url(&settings) {
+        let url = proxy
+            .parse::<tauri::Url>()
+            .map_err(|e| format!("Некорректный адрес прокси: {}", e))?;
+        builder = builder.proxy(url);
+    }
+
+    if let Some(timeout) = settings.request_timeout_secs {
+        builder = builder.timeout(Duration::from_secs(timeout));
+    }
+
+    let certificates = root_certificates(&settings)?;
+    let connect_timeout = settings.connect_timeout_secs.map(Duration::from_secs);
+    builder = builder.configure_client(move |mut client| {
+        for certificate in &certificates {
+            client = client.add_root_certificate(certificate.clone());
+        }
+        if let Some(connect_timeout) = connect_timeout {
+            client = client.connect_timeout(connect_timeout);
+        }
+        client
+    });
+
+    builder.build().map_err(|e| e.to_string())
+}
+
 #[cfg(not(target_os = "linux"))]
 #[tauri::command]
 pub async fn install_update(app: tauri::AppHandle) -> Result<(), String> {
-    let updater = app.updater_builder().build().map_err(|e| e.to_string())?;
+    crate::network::ensure_online()?;
+    let updater = build_updater(&app)?;
 
     match updater.check().await {
         Ok(Some(update)) => {
@@ -0,0 +1,178 @@
+use super::github::GitHubAsset;
+use super::lang_updater::{commit_staged_pack, safe_join, same_version, StagingGuard};
+use crate::localization::InstallReport;
+use crate::manifest::{stored_path, ManifestFile, PackManifest, MANIFEST_VERSION};
+use crate::network::build_client;
+use crate::pack_store::{resolve_pack_dir_for, staging_dir};
+use hytaleru_lib::{prepare_dir, sha256_hex};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::Duration;
+
+pub const PACK_INDEX_ASSET: &str = "pack-index.json";
+const MAX_INDEX_SIZE: u64 = 1024 * 1024;
+const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+const SUPPORTED_HOSTS: [&str; 3] = [
+    "raw.githubusercontent.com",
+    "github.com",
+    "objects.githubusercontent.com",
+];
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackIndex {
+    #[serde(rename = "Version")]
+    pub version: String,
+    #[serde(rename = "BaseUrl")]
+    pub base_url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeltaStatus {
+    pub applied: bool,
+    pub downloaded: usize,
+    pub total: usize,
+    pub error: Option<String>,
+}
+
+pub async fn apply_delta_update(
+    provider: &str,
+    index_asset: &GitHubAsset,
+    latest_version: &str,
+) -> Result<(DeltaStatus, InstallReport), String> {
+    let client = build_client(Duration::from_secs(30))?;
+    let index_bytes = download_bytes(&client, &index_asset.browser_download_url, MAX_INDEX_SIZE).await?;
+    let index: PackIndex = serde_json::from_slice(&index_bytes)
+        .map_err(|e| format!("Ошибка парсинга индекса пакета: {}", e))?;
+
+    if !same_version(&index.version, latest_version)? {
+        return Err("Версия индекса пакета не совпадает с релизом".to_string());
+    }
+
+    let base_url = parse_base_url(&index.base_url)?;
+    let manifest_url = base_url
+        .join("manifest.json")
+        .map_err(|e| format!("Некорректный адрес manifest.json: {}", e))?;
+    let manifest_bytes = download_bytes(&client, manifest_url.as_str(), MAX_INDEX_SIZE).await?;
+    let manifest = PackManifest::from_bytes(&manifest_bytes)?;
+    if !same_version(&manifest.version, latest_version)? {
+        return Err("Версия manifest.json не совпадает с релизом".to_string());
+    }
+    validate_files(&manifest)?;
+
+    let pack_dir = resolve_pack_dir_for(provider)?;
+    let staging_dir = staging_dir("delta")?;
+    prepare_dir(&staging_dir)?;
+    let _guard = StagingGuard(staging_dir.clone());
+
+    let mut downloaded = 0;
+    for file in &manifest.files {
+        let relative = stored_path(&file.path)?;
+        let target = safe_join(&staging_dir, &relative)?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Ошибка создания директории: {}", e))?;
+        }
+
+        let local = safe_join(&pack_dir, &relative)?;
+        let bytes = match fs::read(&local) {
+            Ok(bytes) if verify_file(file, &bytes).is_ok() => bytes,
+            _ => {
+                let url = base_url
+                    .join(&file.path)
+                    .map_err(|e| format!("Некорректный путь в manifest.json: {}", e))?;
+                let bytes = download_bytes(&client, url.as_str(), MAX_FILE_SIZE).await?;
+                verify_file(file, &bytes)?;
+                downloaded += 1;
+                bytes
+            }
+        };
+
+        fs::write(&target, &bytes).map_err(|e| format!("Ошибка записи файла: {}", e))?;
+    }
+
+    let report = commit_staged_pack(&staging_dir, &manifest_bytes, provider, Some(latest_version))?;
+    let status = DeltaStatus {
+        applied: true,
+        downloaded,
+        total: manifest.files.len(),
+        error: None,
+    };
+    Ok((status, report))
+}
+
+fn validate_files(manifest: &PackManifest) -> Result<(), String> {
+    if manifest.schema_version() < MANIFEST_VERSION {
+        return Err("manifest.json не содержит списка файлов".to_string());
+    }
+
+    let has = |prefix: &str| manifest.files.iter().any(|f| f.path.starts_with(prefix));
+    let missing = match (has("Fonts/"), has("Language/ru-RU/")) {
+        (false, false) => Some("Fonts и Language/ru-RU"),
+        (true, true) => None,
+        _ if manifest.is_partial() => None,
+        (false, true) => Some("Fonts"),
+        (true, false) => Some("Language/ru-RU"),
+    };
+    if let Some(missing) = missing {
+        return Err(format!("В manifest.json нет файлов {}", missing));
+    }
+
+    Ok(())
+}
+
+fn parse_base_url(base_url: &str) -> Result<Url, String> {
+    let normalized = if base_url.ends_with('/') {
+        base_url.to_string()
+    } else {
+        format!("{}/", base_url)
+    };
+
+    let url = Url::parse(&normalized)
+        .map_err(|e| format!("Некорректный адрес источника: {}", e))?;
+
+    let supported = url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| SUPPORTED_HOSTS.contains(&host));
+
+    if !supported {
+        return Err("Источник дельта-обновлений не поддерживается".to_string());
+    }
+
+    Ok(url)
+}
+
+fn verify_file(file: &ManifestFile, bytes: &[u8]) -> Result<(), String> {
+    if bytes.len() as u64 != file.size || !sha256_hex(bytes).eq_ignore_ascii_case(&file.sha256) {
+        return Err(format!("Контрольная сумма не совпадает: {}", file.path));
+    }
+    Ok(())
+}
+
+async fn download_bytes(client: &reqwest::Client, url: &str, limit: u64) -> Result<Vec<u8>, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Ошибка загрузки файла: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Ошибка загрузки, статус: {}", response.status()));
+    }
+
+    if response.content_length().is_some_and(|size| size > limit) {
+        return Err("Файл слишком большой".to_string());
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Ошибка чтения файла: {}", e))?;
+
+    if bytes.len() as u64 > limit {
+        return Err("Файл слишком большой".to_string());
+    }
+
+    Ok(bytes.to_vec())
+}
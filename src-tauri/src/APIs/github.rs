@@ -0,0 +1,173 @@
+use crate::network::{build_client, is_offline};
+use crate::providers::Provider;
+use crate::settings::load_settings;
+use hytaleru_lib::{get_data_dir, unix_timestamp, write_atomic};
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const GITHUB_API_URL: &str = "https://api.github.com/repos";
+const CACHE_TTL_SECS: u64 = 15 * 60;
+const DEFAULT_BACKOFF_SECS: u64 = 60;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GitHubRelease {
+    pub tag_name: String,
+    pub name: String,
+    pub body: Option<String>,
+    pub published_at: String,
+    pub assets: Vec<GitHubAsset>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GitHubAsset {
+    pub name: String,
+    pub browser_download_url: String,
+    pub size: u64,
+    pub download_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+struct ReleaseCache {
+    etag: Option<String>,
+    fetched_at: u64,
+    blocked_until: u64,
+    release: Option<GitHubRelease>,
+}
+
+pub async fn fetch_latest_release(provider: &Provider) -> Result<GitHubRelease, String> {
+    let now = unix_timestamp()?;
+    let mut cache = load_cache(&provider.id);
+
+    if is_offline() {
+        return cache
+            .release
+            .ok_or("Включён автономный режим, а сохранённых данных о релизе нет".to_string());
+    }
+
+    if let Some(release) = &cache.release {
+        if now.saturating_sub(cache.fetched_at) < CACHE_TTL_SECS {
+            return Ok(release.clone());
+        }
+    }
+
+    if cache.blocked_until > now {
+        return cache.release.ok_or(format!(
+            "Превышен лимит запросов к GitHub API, повторите через {} сек.",
+            cache.blocked_until - now
+        ));
+    }
+
+    let client = build_client(Duration::from_secs(20))?;
+    let mut request = client
+        .get(format!("{}/{}/releases/latest", GITHUB_API_URL, provider.repository))
+        .header("Accept", "application/vnd.github+json");
+
+    if let (Some(etag), Some(_)) = (&cache.etag, &cache.release) {
+        request = request.header("If-None-Match", etag);
+    }
+
+    if let Some(token) = load_settings()?.github_token.filter(|t| !t.trim().is_empty()) {
+        request = request.header("Authorization", format!("Bearer {}", token.trim()));
+    }
+
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => {
+            return cache
+                .release
+                .ok_or(format!("Ошибка запроса к GitHub API: {}", e));
+        }
+    };
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    if let Some(until) = rate_limit_block(status, &headers, now) {
+        cache.blocked_until = until;
+    }
+
+    if status == StatusCode::NOT_MODIFIED {
+        if let Some(release) = cache.release.clone() {
+            cache.fetched_at = now;
+            save_cache(&provider.id, &cache);
+            return Ok(release);
+        }
+    }
+
+    if !status.is_success() {
+        save_cache(&provider.id, &cache);
+        return cache
+            .release
+            .ok_or(format!("GitHub API вернул статус: {}", status));
+    }
+
+    let release = match response.json::<GitHubRelease>().await {
+        Ok(release) => release,
+        Err(e) => {
+            save_cache(&provider.id, &cache);
+            return cache.release.ok_or(format!("Ошибка парсинга JSON: {}", e));
+        }
+    };
+
+    cache.etag = headers
+        .get("ETag")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    cache.fetched_at = now;
+    cache.release = Some(release.clone());
+    save_cache(&provider.id, &cache);
+
+    Ok(release)
+}
+
+pub fn cached_release(provider: &Provider) -> Option<GitHubRelease> {
+    load_cache(&provider.id).release
+}
+
+fn rate_limit_block(status: StatusCode, headers: &HeaderMap, now: u64) -> Option<u64> {
+    let header_u64 = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+    };
+
+    let limited = status == StatusCode::TOO_MANY_REQUESTS
+        || (status == StatusCode::FORBIDDEN && header_u64("X-RateLimit-Remaining") == Some(0));
+
+    if let Some(retry_after) = header_u64("Retry-After") {
+        if limited {
+            return Some(now + retry_after);
+        }
+    }
+
+    if header_u64("X-RateLimit-Remaining") == Some(0) {
+        return Some(header_u64("X-RateLimit-Reset").unwrap_or(now + DEFAULT_BACKOFF_SECS));
+    }
+
+    limited.then_some(now + DEFAULT_BACKOFF_SECS)
+}
+
+fn cache_path(provider: &str) -> Result<PathBuf, String> {
+    let dir = get_data_dir()?.join("cache");
+    fs::create_dir_all(&dir).map_err(|e| format!("Ошибка создания директории: {}", e))?;
+    Ok(dir.join(format!("github_release_{}.json", provider)))
+}
+
+fn load_cache(provider: &str) -> ReleaseCache {
+    cache_path(provider)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(provider: &str, cache: &ReleaseCache) {
+    if let (Ok(path), Ok(content)) = (cache_path(provider), serde_json::to_vec_pretty(cache)) {
+        let _ = write_atomic(&path, &content);
+    }
+}
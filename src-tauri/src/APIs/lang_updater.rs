@@ -1,35 +1,31 @@
-use hytaleru_lib::resolve_assets_dir;
+use super::delta_update::{apply_delta_update, DeltaStatus, PACK_INDEX_ASSET};
+use super::github::{cached_release, fetch_latest_release, GitHubAsset, GitHubRelease};
+use crate::game_build::{compatibility_report, ensure_compatible, CompatibilityReport};
+use crate::lang::read_lang_tree;
+use crate::lang::validate::{ensure_valid_strings, KeyProblem};
+use crate::localization::InstallReport;
+use crate::manifest::{PackManifest, PACK_LOCALE};
+use crate::network::{build_client, ensure_online, is_offline};
+use crate::pack_archive;
+use crate::pack_store::{
+    original_fonts_dir, resolve_pack_dir_for, staging_dir, store_version, user_original_fonts_dir,
+};
+use crate::profiles::{apply_to_profiles, ProfileApplyResult};
+use crate::providers::{find_provider, provider_or_active, Provider};
+use crate::settings::load_settings;
+use crate::version::Version;
+use hytaleru_lib::{copy_dir_recursive, prepare_dir, write_atomic};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
 use std::fs;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-const GITHUB_API_URL: &str = "https://api.github.com/repos/zzentq/HytaleRussianTranslation/releases/latest";
-const USER_AGENT: &str = "HytaleRU-App";
-const SHARED_PREFIX: &str = "install/release/package/game/latest/Client/Data/Shared/";
-const MAX_ARCHIVE_SIZE: u64 = 50 * 1024 * 1024;
-const MAX_ENTRY_SIZE: u64 = 10 * 1024 * 1024;
+const PACK_EXTENSIONS: [&str; 3] = [".zip", ".tar.gz", ".tar.zst"];
+pub const MAX_ARCHIVE_SIZE: u64 = 50 * 1024 * 1024;
+pub const MAX_ENTRY_SIZE: u64 = 10 * 1024 * 1024;
 const MAX_MANIFEST_SIZE: u64 = 256 * 1024;
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct GitHubRelease {
-    pub tag_name: String,
-    pub name: String,
-    pub body: Option<String>,
-    pub published_at: String,
-    pub assets: Vec<GitHubAsset>,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct GitHubAsset {
-    pub name: String,
-    pub browser_download_url: String,
-    pub size: u64,
-    pub download_count: u64,
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LocalizationUpdateInfo {
     pub current_version: Option<String>,
@@ -39,13 +35,40 @@ pub struct LocalizationUpdateInfo {
     pub changelog: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LocalPackSummary {
+    pub name: Option<String>,
+    pub version: String,
+    pub description: Option<String>,
+    pub authors: Vec<String>,
+    pub provider: String,
+    pub current_version: Option<String>,
+    pub font_files: usize,
+    pub language_files: usize,
+    pub compatibility: Option<CompatibilityReport>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LocalizationUpdateOutcome {
+    pub updated: bool,
+    pub profiles: Vec<ProfileApplyResult>,
+    pub delta: Option<DeltaStatus>,
+    pub warnings: Vec<String>,
+    pub problems: Vec<KeyProblem>,
+}
+
 #[tauri::command]
-pub async fn check_localization_updates() -> Result<Option<LocalizationUpdateInfo>, String> {
-    let release = fetch_latest_release().await?;
-    let asset = select_zip_asset(&release)?;
-    let current_version = get_current_localization_version()?;
+pub async fn check_localization_updates(provider: Option<String>) -> Result<Option<LocalizationUpdateInfo>, String> {
+    if is_offline() {
+        return Ok(None);
+    }
+
+    let provider = provider_or_active(provider)?;
+    let release = fetch_latest_release(&provider).await?;
+    let asset = select_pack_asset(&release, &provider)?;
+    let current_version = get_current_localization_version(&provider)?;
     let latest_version = normalize_version(&release.tag_name);
-    let update_available = is_update_available(&current_version, &latest_version);
+    let update_available = is_update_available(&current_version, &latest_version)?;
     if !update_available {
         println!("Обновлений локализации не найдено");
     }
@@ -60,36 +83,41 @@ pub async fn check_localization_updates() -> Result<Option<LocalizationUpdateInf
 }
 
 #[tauri::command]
-pub async fn auto_update_localization() -> Result<bool, String> {
-    let release = fetch_latest_release().await?;
-    let asset = select_zip_asset(&release)?;
-    let current_version = get_current_localization_version()?;
+pub async fn auto_update_localization(provider: Option<String>) -> Result<LocalizationUpdateOutcome, String> {
+    if is_offline() {
+        return Ok(LocalizationUpdateOutcome::not_updated());
+    }
+
+    let provider = provider_or_active(provider)?;
+    let release = fetch_latest_release(&provider).await?;
+    let asset = select_pack_asset(&release, &provider)?;
+    let current_version = get_current_localization_version(&provider)?;
     let latest_version = normalize_version(&release.tag_name);
 
-    if !is_update_available(&current_version, &latest_version) {
+    if !is_update_available(&current_version, &latest_version)? {
         println!("Обновлений локализации не найдено");
-        return Ok(false);
+        return Ok(LocalizationUpdateOutcome::not_updated());
     }
 
-    let zip_path = download_zip(&asset.browser_download_url, asset.size).await?;
-    let result = install_localization_update(&zip_path, &latest_version);
-    let _ = fs::remove_file(&zip_path);
-    result?;
-
-    Ok(true)
+    let (delta, report) = install_release(&provider, &release, asset, &latest_version).await?;
+    LocalizationUpdateOutcome::after_update(&provider, delta, report)
 }
 
 #[tauri::command]
 pub async fn download_localization_update(
     version: String,
     download_url: String,
-) -> Result<(), String> {
-    let release = fetch_latest_release().await?;
-    let asset = select_zip_asset(&release)?;
+    provider: Option<String>,
+) -> Result<LocalizationUpdateOutcome, String> {
+    ensure_online()?;
+    let provider = provider_or_active(provider)?;
+    let release = match cached_release(&provider) {
+        Some(release) => release,
+        None => fetch_latest_release(&provider).await?,
+    };
+    let asset = select_pack_asset(&release, &provider)?;
     let latest_version = normalize_version(&release.tag_name);
-    let requested_version = normalize_version(&version);
-
-    if requested_version != latest_version {
+    if !same_version(&version, &latest_version)? {
         return Err("Запрошенная версия не совпадает с последним релизом".to_string());
     }
 
@@ -97,77 +125,157 @@ pub async fn download_localization_update(
         return Err("Ссылка на загрузку не совпадает с последним релизом".to_string());
     }
 
-    let zip_path = download_zip(&asset.browser_download_url, asset.size).await?;
-    let result = install_localization_update(&zip_path, &latest_version);
-    let _ = fs::remove_file(&zip_path);
-    result
+    let (delta, report) = install_release(&provider, &release, asset, &latest_version).await?;
+    LocalizationUpdateOutcome::after_update(&provider, delta, report)
 }
 
-fn get_current_localization_version() -> Result<Option<String>, String> {
-    let assets_dir = resolve_assets_dir()?;
-    let manifest_file = assets_dir.join("manifest.json");
+#[tauri::command]
+pub async fn install_provider(id: String) -> Result<LocalizationUpdateOutcome, String> {
+    ensure_online()?;
+    let provider = find_provider(&id)?;
+    let release = fetch_latest_release(&provider).await?;
+    let asset = select_pack_asset(&release, &provider)?;
+    let latest_version = normalize_version(&release.tag_name);
 
-    if !manifest_file.exists() {
-        return Ok(None);
-    }
+    let (delta, report) = install_release(&provider, &release, asset, &latest_version).await?;
+    LocalizationUpdateOutcome::after_update(&provider, delta, report)
+}
+
+#[tauri::command]
+pub fn inspect_local_pack(path: String, provider: Option<String>) -> Result<LocalPackSummary, String> {
+    let provider = provider_or_active(provider)?;
+    let archive = local_archive_path(&path)?;
+
+    let staging_dir = staging_dir("local")?;
+    prepare_dir(&staging_dir)?;
+    let _guard = StagingGuard(staging_dir.clone());
+
+    let manifest = PackManifest::from_bytes(&extract_archive(&archive, &staging_dir)?)?;
+    manifest.parsed_version()?;
+
+    let compatibility = match crate::gamepath::hytale_game_dir() {
+        Ok(game) => Some(compatibility_report(&manifest, &game)?),
+        Err(_) => None,
+    };
+
+    Ok(LocalPackSummary {
+        name: manifest.name.clone(),
+        version: normalize_version(&manifest.version),
+        description: manifest.description.clone(),
+        authors: manifest.authors.iter().map(|author| author.name.clone()).collect(),
+        current_version: get_current_localization_version(&provider)?,
+        provider: provider.id,
+        font_files: count_files(&staging_dir.join("Fonts").join("withRU")),
+        language_files: count_files(&staging_dir.join("Language").join("ru-RU")),
+        compatibility,
+    })
+}
 
-    let manifest_content = fs::read_to_string(&manifest_file)
-        .map_err(|e| format!("Ошибка чтения manifest.json: {}", e))?;
+#[tauri::command]
+pub fn install_local_pack(path: String, provider: Option<String>) -> Result<LocalizationUpdateOutcome, String> {
+    let provider = provider_or_active(provider)?;
+    let archive = local_archive_path(&path)?;
+
+    let staging_dir = staging_dir("local")?;
+    prepare_dir(&staging_dir)?;
+    let _guard = StagingGuard(staging_dir.clone());
 
-    let manifest: serde_json::Value = serde_json::from_str(&manifest_content)
-        .map_err(|e| format!("Ошибка парсинга manifest.json: {}", e))?;
+    let manifest = extract_archive(&archive, &staging_dir)?;
+    let report = commit_staged_pack(&staging_dir, &manifest, &provider.id, None)?;
+    LocalizationUpdateOutcome::after_update(&provider, None, report)
+}
+
+impl LocalizationUpdateOutcome {
+    fn not_updated() -> Self {
+        LocalizationUpdateOutcome {
+            updated: false,
+            profiles: Vec::new(),
+            delta: None,
+            warnings: Vec::new(),
+            problems: Vec::new(),
+        }
+    }
 
-    match manifest.get("Version").and_then(|v| v.as_str()) {
-        Some(version) => Ok(Some(version.to_string())),
-        None => Err("Поле Version не найдено в manifest.json".to_string()),
+    fn after_update(provider: &Provider, delta: Option<DeltaStatus>, report: InstallReport) -> Result<Self, String> {
+        let profiles = if load_settings()?.auto_apply_updates {
+            apply_to_profiles(Some(&provider.id))?
+        } else {
+            Vec::new()
+        };
+
+        Ok(LocalizationUpdateOutcome {
+            updated: true,
+            profiles,
+            delta,
+            warnings: report.warnings,
+            problems: report.problems,
+        })
     }
 }
 
-async fn fetch_latest_release() -> Result<GitHubRelease, String> {
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(20))
-        .build()
-        .map_err(|e| format!("Ошибка инициализации клиента: {}", e))?;
+async fn install_release(
+    provider: &Provider,
+    release: &GitHubRelease,
+    asset: &GitHubAsset,
+    latest_version: &str,
+) -> Result<(Option<DeltaStatus>, InstallReport), String> {
+    let delta = match select_index_asset(release) {
+        Some(index_asset) => match apply_delta_update(&provider.id, index_asset, latest_version).await {
+            Ok((status, report)) => return Ok((Some(status), report)),
+            Err(e) => Some(DeltaStatus {
+                error: Some(e),
+                ..DeltaStatus::default()
+            }),
+        },
+        None => None,
+    };
+
+    let archive_path = download_archive(&asset.browser_download_url, asset.size).await?;
+    let result = install_localization_update(&archive_path, &provider.id, latest_version);
+    let _ = fs::remove_file(&archive_path);
+    result.map(|report| (delta, report))
+}
 
-    let response = client
-        .get(GITHUB_API_URL)
-        .header("User-Agent", USER_AGENT)
-        .header("Accept", "application/vnd.github+json")
-        .send()
-        .await
-        .map_err(|e| format!("Ошибка запроса к GitHub API: {}", e))?;
+fn get_current_localization_version(provider: &Provider) -> Result<Option<String>, String> {
+    let pack_dir = match resolve_pack_dir_for(&provider.id) {
+        Ok(dir) => dir,
+        Err(_) => return Ok(None),
+    };
 
-    if !response.status().is_success() {
-        return Err(format!("GitHub API вернул статус: {}", response.status()));
+    if !pack_dir.join("manifest.json").exists() {
+        return Ok(None);
     }
 
-    response
-        .json::<GitHubRelease>()
-        .await
-        .map_err(|e| format!("Ошибка парсинга JSON: {}", e))
+    PackManifest::read_from(&pack_dir).map(|manifest| Some(manifest.version))
 }
 
-fn select_zip_asset(release: &GitHubRelease) -> Result<&GitHubAsset, String> {
+fn select_pack_asset<'a>(release: &'a GitHubRelease, provider: &Provider) -> Result<&'a GitHubAsset, String> {
+    PACK_EXTENSIONS
+        .iter()
+        .find_map(|extension| {
+            release
+                .assets
+                .iter()
+                .find(|asset| asset.name.ends_with(extension) && asset.name.contains(&provider.asset_pattern))
+        })
+        .ok_or("Архив релиза не найден".to_string())
+}
+
+fn select_index_asset(release: &GitHubRelease) -> Option<&GitHubAsset> {
     release
         .assets
         .iter()
-        .find(|asset| asset.name.ends_with(".zip") && asset.name.contains("Hytale-Russian"))
-        .ok_or("ZIP файл релиза не найден".to_string())
+        .find(|asset| asset.name == PACK_INDEX_ASSET)
 }
 
-async fn download_zip(download_url: &str, asset_size: u64) -> Result<PathBuf, String> {
+async fn download_archive(download_url: &str, asset_size: u64) -> Result<PathBuf, String> {
     if asset_size > MAX_ARCHIVE_SIZE {
         return Err("Архив слишком большой".to_string());
     }
 
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(30))
-        .build()
-        .map_err(|e| format!("Ошибка инициализации клиента: {}", e))?;
-
+    let client = build_client(Duration::from_secs(30))?;
     let response = client
         .get(download_url)
-        .header("User-Agent", USER_AGENT)
         .send()
         .await
         .map_err(|e| format!("Ошибка скачивания архива: {}", e))?;
@@ -191,92 +299,87 @@ async fn download_zip(download_url: &str, asset_size: u64) -> Result<PathBuf, St
         return Err("Архив слишком большой".to_string());
     }
 
-    let zip_path = create_temp_zip_path()?;
+    let archive_path = create_temp_archive_path()?;
     let mut file = fs::OpenOptions::new()
         .write(true)
         .create_new(true)
-        .open(&zip_path)
+        .open(&archive_path)
         .map_err(|e| format!("Ошибка создания временного файла: {}", e))?;
 
     file.write_all(&bytes)
         .map_err(|e| format!("Ошибка записи архива: {}", e))?;
 
-    Ok(zip_path)
+    Ok(archive_path)
 }
 
-fn create_temp_zip_path() -> Result<PathBuf, String> {
+fn create_temp_archive_path() -> Result<PathBuf, String> {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_err(|_| "Ошибка времени системы")?
         .as_millis();
-    let filename = format!("hytale_ru_update_{}.zip", now);
+    let filename = format!("hytale_ru_update_{}.pack", now);
     Ok(std::env::temp_dir().join(filename))
 }
 
-fn install_localization_update(zip_path: &Path, latest_version: &str) -> Result<(), String> {
-    use zip::ZipArchive;
+fn install_localization_update(
+    archive_path: &Path,
+    provider: &str,
+    latest_version: &str,
+) -> Result<InstallReport, String> {
+    let staging_dir = staging_dir("update")?;
+    prepare_dir(&staging_dir)?;
+    let _guard = StagingGuard(staging_dir.clone());
+
+    let manifest = extract_archive(archive_path, &staging_dir)?;
+    commit_staged_pack(&staging_dir, &manifest, provider, Some(latest_version))
+}
 
-    let assets_dir = resolve_assets_dir()?;
-    ensure_original_fonts(&assets_dir)?;
-    let staging_dir = assets_dir.join(".update_tmp");
+pub fn verify_archive(archive_path: &Path) -> Result<PackManifest, String> {
+    let staging_dir = staging_dir("verify")?;
     prepare_dir(&staging_dir)?;
     let _guard = StagingGuard(staging_dir.clone());
 
+    let manifest = PackManifest::from_bytes(&extract_archive(archive_path, &staging_dir)?)?;
+    manifest.parsed_version()?;
+    manifest.validate_contents(&staging_dir)?;
+    Ok(manifest)
+}
+
+fn extract_archive(archive_path: &Path, staging_dir: &Path) -> Result<Vec<u8>, String> {
     let staging_fonts = staging_dir.join("Fonts").join("withRU");
     let staging_lang = staging_dir.join("Language").join("ru-RU");
-    fs::create_dir_all(&staging_fonts)
-        .map_err(|e| format!("Ошибка создания директории: {}", e))?;
-    fs::create_dir_all(&staging_lang)
-        .map_err(|e| format!("Ошибка создания директории: {}", e))?;
 
-    let file = fs::File::open(zip_path)
-        .map_err(|e| format!("Ошибка открытия ZIP файла: {}", e))?;
-    let mut archive = ZipArchive::new(file)
-        .map_err(|e| format!("Ошибка чтения ZIP архива: {}", e))?;
+    let names = pack_archive::list_files(archive_path)?;
+    let layout = pack_archive::detect_layout(&names)?;
 
     let mut manifest_bytes: Option<Vec<u8>> = None;
     let mut found_fonts = false;
     let mut found_lang = false;
     let mut total_size: u64 = 0;
 
-    for i in 0..archive.len() {
-        let mut entry = archive
-            .by_index(i)
-            .map_err(|e| format!("Ошибка чтения файла из архива: {}", e))?;
-
-        if entry.is_dir() || is_symlink(&entry) {
-            continue;
-        }
-
-        if entry.size() > MAX_ENTRY_SIZE {
+    pack_archive::for_each_file(archive_path, |name, size, entry| {
+        if size > MAX_ENTRY_SIZE {
             return Err("Файл в архиве слишком большой".to_string());
         }
 
-        total_size = total_size.saturating_add(entry.size());
+        total_size = total_size.saturating_add(size);
         if total_size > MAX_ARCHIVE_SIZE {
             return Err("Архив слишком большой".to_string());
         }
 
-        let safe_name = entry
-            .enclosed_name()
-            .ok_or("Небезопасный путь в архиве")?;
-        let name = safe_name
-            .to_string_lossy()
-            .replace('\\', "/");
-
-        if is_manifest_path(&name) {
-            let bytes = read_zip_entry(&mut entry, MAX_MANIFEST_SIZE)?;
+        if is_manifest_path(name) {
+            let bytes = read_entry(entry, size, MAX_MANIFEST_SIZE)?;
             validate_manifest(&bytes)?;
             manifest_bytes = Some(bytes);
-            continue;
+            return Ok(());
         }
 
-        if let Some(relative) = extract_shared_relative(&name) {
+        if let Some(relative) = layout.relative(name) {
             if let Some(fonts_rel) = relative.strip_prefix("Fonts/") {
                 let out_path = safe_join(&staging_fonts, Path::new(fonts_rel))?;
-                write_zip_entry(&mut entry, &out_path)?;
+                write_entry(entry, &out_path)?;
                 found_fonts = true;
-                continue;
+                return Ok(());
             }
 
             if let Some(lang_rel) = relative
@@ -284,44 +387,66 @@ fn install_localization_update(zip_path: &Path, latest_version: &str) -> Result<
                 .or_else(|| relative.strip_prefix("Language/ru_RU/"))
             {
                 let out_path = safe_join(&staging_lang, Path::new(lang_rel))?;
-                write_zip_entry(&mut entry, &out_path)?;
+                write_entry(entry, &out_path)?;
                 found_lang = true;
             }
         }
-    }
+
+        Ok(())
+    })?;
 
     let manifest = manifest_bytes.ok_or("manifest.json не найден в архиве")?;
-    let manifest_version = extract_manifest_version(&manifest)?;
-    if normalize_version(&manifest_version) != normalize_version(latest_version) {
-        return Err("Версия manifest.json не совпадает с релизом".to_string());
-    }
 
-    if !found_fonts {
-        return Err("В архиве нет файлов Fonts".to_string());
+    let missing = match (found_fonts, found_lang) {
+        (false, false) => Some("Fonts и Language/ru-RU"),
+        (true, true) => None,
+        _ if PackManifest::from_bytes(&manifest)?.is_partial() => None,
+        (false, true) => Some("Fonts"),
+        (true, false) => Some("Language/ru-RU"),
+    };
+    if let Some(missing) = missing {
+        return Err(format!(
+            "В архиве нет файлов {} ({}). Найдено: {}",
+            missing,
+            layout.description,
+            pack_archive::summarize_entries(&names)
+        ));
     }
 
-    if !found_lang {
-        return Err("В архиве нет файлов Language/ru-RU".to_string());
+    Ok(manifest)
+}
+
+pub fn commit_staged_pack(
+    staging_dir: &Path,
+    manifest: &[u8],
+    provider: &str,
+    latest_version: Option<&str>,
+) -> Result<InstallReport, String> {
+    let pack_manifest = PackManifest::from_bytes(manifest)?;
+    let manifest_version = pack_manifest.version.clone();
+    pack_manifest.parsed_version()?;
+    pack_manifest.check_app_version()?;
+    pack_manifest.validate_contents(staging_dir)?;
+    if let Some(latest_version) = latest_version {
+        if !same_version(&manifest_version, latest_version)? {
+            return Err("Версия manifest.json не совпадает с релизом".to_string());
+        }
     }
 
-    let assets_fonts = assets_dir.join("Fonts").join("withRU");
-    let assets_lang = assets_dir.join("Language").join("ru-RU");
+    let mut report = InstallReport::default();
+    if let Ok(game) = crate::gamepath::hytale_game_dir() {
+        report.warnings.extend(ensure_compatible(&pack_manifest, &game)?);
+        report.problems = ensure_valid_strings(&game, &read_lang_tree(&staging_dir.join("Language").join(PACK_LOCALE))?)?;
+    }
 
-    replace_dir(&staging_fonts, &assets_fonts)?;
-    replace_dir(&staging_lang, &assets_lang)?;
-    write_atomic(&assets_dir.join("manifest.json"), &manifest)?;
-    Ok(())
+    ensure_original_fonts()?;
+    write_atomic(&staging_dir.join("manifest.json"), manifest)?;
+    store_version(staging_dir, provider, &normalize_version(&manifest_version))?;
+    Ok(report)
 }
 
-fn ensure_original_fonts(assets_dir: &Path) -> Result<(), String> {
-    let original_dir = assets_dir.join("Fonts").join("original");
-    let required = ["Lexend-Bold.json", "Lexend-Bold.png", "Lexend-Bold.ttf"];
-
-    let has_all = required
-        .iter()
-        .all(|name| original_dir.join(name).exists());
-
-    if has_all {
+fn ensure_original_fonts() -> Result<(), String> {
+    if original_fonts_dir().is_some() {
         return Ok(());
     }
 
@@ -331,14 +456,42 @@ fn ensure_original_fonts(assets_dir: &Path) -> Result<(), String> {
         return Err("Папка Fonts в игре не найдена".to_string());
     }
 
+    let original_dir = user_original_fonts_dir()?;
     prepare_dir(&original_dir)?;
     copy_dir_recursive(&game_fonts, &original_dir)?;
     Ok(())
 }
 
-fn extract_shared_relative(path: &str) -> Option<&str> {
-    path.find(SHARED_PREFIX)
-        .map(|pos| &path[(pos + SHARED_PREFIX.len())..])
+fn local_archive_path(path: &str) -> Result<PathBuf, String> {
+    let archive = PathBuf::from(path);
+    let metadata = fs::metadata(&archive).map_err(|e| format!("Файл архива недоступен: {}", e))?;
+    if !metadata.is_file() {
+        return Err("Указанный путь не является файлом".to_string());
+    }
+
+    if metadata.len() > MAX_ARCHIVE_SIZE {
+        return Err("Архив слишком большой".to_string());
+    }
+
+    Ok(archive)
+}
+
+fn count_files(dir: &Path) -> usize {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        count_files(&path)
+                    } else {
+                        1
+                    }
+                })
+                .sum()
+        })
+        .unwrap_or(0)
 }
 
 fn is_manifest_path(path: &str) -> bool {
@@ -346,40 +499,23 @@ fn is_manifest_path(path: &str) -> bool {
 }
 
 fn validate_manifest(bytes: &[u8]) -> Result<(), String> {
-    let manifest: serde_json::Value = serde_json::from_slice(bytes)
-        .map_err(|e| format!("Ошибка парсинга manifest.json: {}", e))?;
-
-    if manifest.get("Version").and_then(|v| v.as_str()).is_none() {
-        return Err("Поле Version не найдено в manifest.json".to_string());
-    }
-
-    Ok(())
+    PackManifest::from_bytes(bytes).map(|_| ())
 }
 
-fn extract_manifest_version(bytes: &[u8]) -> Result<String, String> {
-    let manifest: serde_json::Value = serde_json::from_slice(bytes)
-        .map_err(|e| format!("Ошибка парсинга manifest.json: {}", e))?;
-
-    manifest
-        .get("Version")
-        .and_then(|v| v.as_str())
-        .map(|v| v.to_string())
-        .ok_or("Поле Version не найдено в manifest.json".to_string())
-}
-
-fn read_zip_entry(entry: &mut zip::read::ZipFile, limit: u64) -> Result<Vec<u8>, String> {
-    if entry.size() > limit {
+fn read_entry(entry: &mut dyn Read, size: u64, limit: u64) -> Result<Vec<u8>, String> {
+    if size > limit {
         return Err("Файл в архиве слишком большой".to_string());
     }
 
-    let mut buffer = Vec::with_capacity(entry.size() as usize);
+    let mut buffer = Vec::with_capacity(size as usize);
     entry
+        .take(limit)
         .read_to_end(&mut buffer)
         .map_err(|e| format!("Ошибка чтения файла из архива: {}", e))?;
     Ok(buffer)
 }
 
-fn write_zip_entry(entry: &mut zip::read::ZipFile, path: &Path) -> Result<(), String> {
+fn write_entry(entry: &mut dyn Read, path: &Path) -> Result<(), String> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .map_err(|e| format!("Ошибка создания директории: {}", e))?;
@@ -397,80 +533,7 @@ fn write_zip_entry(entry: &mut zip::read::ZipFile, path: &Path) -> Result<(), St
     Ok(())
 }
 
-fn prepare_dir(path: &Path) -> Result<(), String> {
-    if path.exists() {
-        fs::remove_dir_all(path)
-            .map_err(|e| format!("Ошибка очистки директории: {}", e))?;
-    }
-    fs::create_dir_all(path)
-        .map_err(|e| format!("Ошибка создания директории: {}", e))?;
-    Ok(())
-}
-
-fn replace_dir(src: &Path, dst: &Path) -> Result<(), String> {
-    if dst.exists() {
-        fs::remove_dir_all(dst)
-            .map_err(|e| format!("Ошибка удаления директории: {}", e))?;
-    }
-
-    if let Some(parent) = dst.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Ошибка создания директории: {}", e))?;
-    }
-
-    if fs::rename(src, dst).is_err() {
-        copy_dir_recursive(src, dst)?;
-        fs::remove_dir_all(src)
-            .map_err(|e| format!("Ошибка удаления временной директории: {}", e))?;
-    }
-
-    Ok(())
-}
-
-fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
-    fs::create_dir_all(dst)
-        .map_err(|e| format!("Ошибка создания директории: {}", e))?;
-
-    for entry in fs::read_dir(src)
-        .map_err(|e| format!("Ошибка чтения директории: {}", e))?
-    {
-        let entry = entry.map_err(|e| format!("Ошибка чтения записи: {}", e))?;
-        let entry_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
-
-        if entry_path.is_dir() {
-            copy_dir_recursive(&entry_path, &dst_path)?;
-        } else {
-            fs::copy(&entry_path, &dst_path)
-                .map_err(|e| format!("Ошибка копирования файла: {}", e))?;
-        }
-    }
-
-    Ok(())
-}
-
-fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), String> {
-    let temp_path = path.with_extension(format!(
-        "tmp_{}",
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| "Ошибка времени системы")?
-            .as_millis()
-    ));
-
-    if let Some(parent) = temp_path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Ошибка создания директории: {}", e))?;
-    }
-
-    fs::write(&temp_path, bytes)
-        .map_err(|e| format!("Ошибка записи файла: {}", e))?;
-    fs::rename(&temp_path, path)
-        .map_err(|e| format!("Ошибка сохранения файла: {}", e))?;
-    Ok(())
-}
-
-fn safe_join(base: &Path, relative: &Path) -> Result<PathBuf, String> {
+pub fn safe_join(base: &Path, relative: &Path) -> Result<PathBuf, String> {
     if relative.components().any(|c| {
         matches!(
             c,
@@ -484,50 +547,23 @@ fn safe_join(base: &Path, relative: &Path) -> Result<PathBuf, String> {
     Ok(base.join(relative))
 }
 
-fn is_symlink(entry: &zip::read::ZipFile) -> bool {
-    if let Some(mode) = entry.unix_mode() {
-        (mode & 0o170000) == 0o120000
-    } else {
-        false
-    }
-}
-
-fn normalize_version(version: &str) -> String {
+pub fn normalize_version(version: &str) -> String {
     version.trim().trim_start_matches('v').to_string()
 }
 
-fn is_update_available(current: &Option<String>, latest: &str) -> bool {
-    match current.as_ref().map(|v| normalize_version(v)) {
-        Some(current_version) => compare_versions(&current_version, latest) == Ordering::Less,
-        None => true,
-    }
+pub fn same_version(left: &str, right: &str) -> Result<bool, String> {
+    Ok(Version::parse(left)? == Version::parse(right)?)
 }
 
-fn compare_versions(left: &str, right: &str) -> Ordering {
-    let left_parts = parse_version(left);
-    let right_parts = parse_version(right);
-    let max_len = left_parts.len().max(right_parts.len());
-
-    for i in 0..max_len {
-        let l = *left_parts.get(i).unwrap_or(&0);
-        let r = *right_parts.get(i).unwrap_or(&0);
-        match l.cmp(&r) {
-            Ordering::Equal => continue,
-            other => return other,
-        }
+fn is_update_available(current: &Option<String>, latest: &str) -> Result<bool, String> {
+    let latest = Version::parse(latest)?;
+    match current {
+        Some(current_version) => Ok(Version::parse(current_version)? < latest),
+        None => Ok(true),
     }
-
-    Ordering::Equal
-}
-
-fn parse_version(value: &str) -> Vec<u64> {
-    value
-        .split('.')
-        .map(|part| part.parse::<u64>().unwrap_or(0))
-        .collect()
 }
 
-struct StagingGuard(PathBuf);
+pub struct StagingGuard(pub PathBuf);
 
 impl Drop for StagingGuard {
     fn drop(&mut self) {
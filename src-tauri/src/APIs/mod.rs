@@ -1,2 +1,4 @@
 pub mod app_updater;
+pub mod delta_update;
+pub mod github;
 pub mod lang_updater;
\ No newline at end of file
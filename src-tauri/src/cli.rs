@@ -0,0 +1,418 @@
+use crate::gamepath::hytale_game_dir;
+use crate::lang::coverage::game_coverage;
+use crate::lang::exchange::{export_units, import_bytes, render, ExchangeFormat};
+use crate::lang::glossary::{Glossary, GLOSSARY_FILE};
+use crate::lang::lint::{lint_dir, translation_dir};
+use crate::lang::stale::{accept_key, record_dir, SourceHashes, SOURCE_HASHES_FILE};
+use crate::lang::typography::typography_dir;
+use crate::lang::validate::validate_trees;
+use crate::lang::{game_lang_dir, game_source_tree, pack_lang_tree, read_lang_tree, LangFile, SOURCE_LOCALE};
+use crate::pack_builder::{build_pack, PackBuildOptions};
+use crate::settings::load_settings;
+use hytaleru_lib::write_atomic;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "Использование:
+  hytale-ru-app pack build <папка> [--out <папка>] [--name <имя>] [--sign-key <файл>] [--base-url <url>]
+  hytale-ru-app lang coverage [--game <папка>] [--format json|markdown] [--out <файл>]
+  hytale-ru-app lang validate [--game <папка>] [--format text|json] [--out <файл>]
+  hytale-ru-app lang lint <папка> [--game <папка>] [--config <файл>] [--fix] [--format text|json] [--out <файл>]
+  hytale-ru-app lang typography <папка> [--config <файл>] [--fix] [--format text|json] [--out <файл>]
+  hytale-ru-app lang glossary <папка> [--glossary <файл>] [--game <папка>] [--format text|json] [--out <файл>]
+  hytale-ru-app lang export <папка> --format po|xliff|csv [--game <папка>] [--out <файл>]
+  hytale-ru-app lang import <папка> --from <файл> [--format po|xliff|csv] [--game <папка>]
+  hytale-ru-app lang stale <папка> [--game <папка>] [--record] [--accept] [--format text|json] [--out <файл>]";
+
+pub fn run(args: &[String]) -> Option<i32> {
+    let command: Vec<&str> = args.iter().take(2).map(String::as_str).collect();
+
+    let result = match command.as_slice() {
+        ["pack", "build"] => pack_build(&args[2..]),
+        ["lang", "coverage"] => lang_coverage(&args[2..]),
+        ["lang", "validate"] => lang_validate(&args[2..]),
+        ["lang", "lint"] => lang_lint(&args[2..]),
+        ["lang", "typography"] => lang_typography(&args[2..]),
+        ["lang", "glossary"] => lang_glossary(&args[2..]),
+        ["lang", "export"] => lang_export(&args[2..]),
+        ["lang", "import"] => lang_import(&args[2..]),
+        ["lang", "stale"] => lang_stale(&args[2..]),
+        ["pack", ..] | ["lang", ..] | ["help"] | ["--help"] => Err(USAGE.to_string()),
+        _ => return None,
+    };
+
+    match result {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("{}", e);
+            Some(1)
+        }
+    }
+}
+
+fn pack_build(args: &[String]) -> Result<(), String> {
+    let mut flags = Flags::parse(args, &["--out", "--name", "--sign-key", "--base-url"], &[])?;
+    let [source_dir] = flags.positional.as_slice() else {
+        return Err(USAGE.to_string());
+    };
+    let options = PackBuildOptions {
+        source_dir: source_dir.clone(),
+        output_dir: flags.values.remove("--out"),
+        name: flags.values.remove("--name"),
+        signing_key: flags.values.remove("--sign-key"),
+        base_url: flags.values.remove("--base-url"),
+    };
+
+    let result = build_pack(&options)?;
+    println!("Версия: {}", result.version);
+    println!("Архив: {} ({} файлов)", result.archive, result.files);
+    println!("SHA-256: {}", result.sha256);
+    println!("Файл хеша: {}", result.hash_file);
+    if let (Some(signature), Some(public_key)) = (&result.signature_file, &result.public_key) {
+        println!("Подпись: {}", signature);
+        println!("Открытый ключ: {}", public_key);
+    }
+    if let Some(index) = &result.index_file {
+        println!("Индекс пакета: {}", index);
+    }
+    Ok(())
+}
+
+struct Flags {
+    values: BTreeMap<String, String>,
+    switches: BTreeSet<String>,
+    positional: Vec<String>,
+}
+
+impl Flags {
+    fn parse(args: &[String], with_value: &[&str], switches: &[&str]) -> Result<Self, String> {
+        let mut flags = Flags {
+            values: BTreeMap::new(),
+            switches: BTreeSet::new(),
+            positional: Vec::new(),
+        };
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            if with_value.contains(&arg.as_str()) {
+                let value = iter
+                    .next()
+                    .ok_or(format!("Не указано значение для {}\n{}", arg, USAGE))?;
+                flags.values.insert(arg.clone(), value.clone());
+            } else if switches.contains(&arg.as_str()) {
+                flags.switches.insert(arg.clone());
+            } else if arg.starts_with("--") {
+                return Err(format!("Неизвестный параметр {}\n{}", arg, USAGE));
+            } else {
+                flags.positional.push(arg.clone());
+            }
+        }
+
+        Ok(flags)
+    }
+}
+
+struct LangArgs {
+    path: Option<PathBuf>,
+    game: Option<PathBuf>,
+    format: Option<String>,
+    out: Option<PathBuf>,
+    config: Option<PathBuf>,
+    glossary: Option<PathBuf>,
+    from: Option<PathBuf>,
+    fix: bool,
+    record: bool,
+    accept: bool,
+}
+
+impl LangArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut flags = Flags::parse(
+            args,
+            &["--game", "--format", "--out", "--config", "--glossary", "--from"],
+            &["--fix", "--record", "--accept"],
+        )?;
+        if flags.positional.len() > 1 {
+            return Err(USAGE.to_string());
+        }
+        let mut path = |flag: &str| flags.values.remove(flag).map(PathBuf::from);
+
+        Ok(LangArgs {
+            game: path("--game"),
+            out: path("--out"),
+            config: path("--config"),
+            glossary: path("--glossary"),
+            from: path("--from"),
+            format: flags.values.remove("--format"),
+            path: flags.positional.pop().map(PathBuf::from),
+            fix: flags.switches.contains("--fix"),
+            record: flags.switches.contains("--record"),
+            accept: flags.switches.contains("--accept"),
+        })
+    }
+
+    fn game_dir(&self) -> Result<PathBuf, String> {
+        match &self.game {
+            Some(game) => Ok(game.clone()),
+            None => hytale_game_dir(),
+        }
+    }
+
+    fn source_tree(&self) -> Result<BTreeMap<String, LangFile>, String> {
+        match self.game_dir() {
+            Ok(game) => read_lang_tree(&game_lang_dir(&game, SOURCE_LOCALE)),
+            Err(_) => Ok(BTreeMap::new()),
+        }
+    }
+
+    fn required_source_tree(&self) -> Result<BTreeMap<String, LangFile>, String> {
+        read_lang_tree(&game_lang_dir(&self.game_dir()?, SOURCE_LOCALE))
+    }
+}
+
+fn lang_coverage(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let report = game_coverage(&args.game_dir()?)?.render(args.format.as_deref().unwrap_or("markdown"))?;
+    print_or_write(&report, args.out.as_deref())
+}
+
+fn lang_validate(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let game = args.game_dir()?;
+    let source = read_lang_tree(&game_lang_dir(&game, SOURCE_LOCALE))?;
+    let problems = validate_trees(&source, &pack_lang_tree(&game)?);
+
+    let report = match args.format.as_deref().unwrap_or("text") {
+        "json" => serde_json::to_string_pretty(&problems)
+            .map_err(|e| format!("Ошибка сериализации отчёта: {}", e))?,
+        "text" => problems
+            .iter()
+            .map(|problem| format!("{}: {}: {}", problem.file, problem.key, problem.message))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        format => return Err(format!("Неизвестный формат отчёта: {}", format)),
+    };
+    print_or_write(&report, args.out.as_deref())?;
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Найдено проблем: {}", problems.len()))
+    }
+}
+
+fn lang_lint(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let path = args.path.clone().ok_or(USAGE)?;
+    let config = match &args.config {
+        Some(config) => read_config(config)?,
+        None => load_settings()?.lint,
+    };
+
+    let report = lint_dir(&translation_dir(&path), &config, &args.source_tree()?, args.fix)?;
+    let output = match args.format.as_deref().unwrap_or("text") {
+        "json" => serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Ошибка сериализации отчёта: {}", e))?,
+        "text" => report
+            .issues
+            .iter()
+            .map(|issue| {
+                format!(
+                    "{}:{}: {:?} [{}] {}{}{}",
+                    issue.file,
+                    issue.line,
+                    issue.severity,
+                    issue.rule.name(),
+                    issue.key.as_deref().map(|key| format!("{}: ", key)).unwrap_or_default(),
+                    issue.message,
+                    if issue.fixed { " (исправлено)" } else { "" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        format => return Err(format!("Неизвестный формат отчёта: {}", format)),
+    };
+    print_or_write(&output, args.out.as_deref())?;
+
+    if report.errors > 0 {
+        Err(format!("Ошибок: {}, предупреждений: {}", report.errors, report.warnings))
+    } else {
+        Ok(())
+    }
+}
+
+fn lang_typography(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let path = args.path.clone().ok_or(USAGE)?;
+    let config = match &args.config {
+        Some(config) => read_config(config)?,
+        None => load_settings()?.typography,
+    };
+
+    let changes = typography_dir(&translation_dir(&path), &config, args.fix)?;
+    let output = match args.format.as_deref().unwrap_or("text") {
+        "json" => serde_json::to_string_pretty(&changes)
+            .map_err(|e| format!("Ошибка сериализации отчёта: {}", e))?,
+        "text" => changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "{}:{}: {}\n  - {:?}\n  + {:?}",
+                    change.file, change.line, change.key, change.before, change.after
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        format => return Err(format!("Неизвестный формат отчёта: {}", format)),
+    };
+    print_or_write(&output, args.out.as_deref())?;
+
+    if args.fix || changes.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Строк с нарушениями типографики: {}", changes.len()))
+    }
+}
+
+fn lang_glossary(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let path = args.path.clone().ok_or(USAGE)?;
+    let glossary = Glossary::read(&args.glossary.clone().unwrap_or_else(|| path.join(GLOSSARY_FILE)))?;
+    let issues = glossary.check(&args.source_tree()?, &read_lang_tree(&translation_dir(&path))?);
+
+    let output = match args.format.as_deref().unwrap_or("text") {
+        "json" => serde_json::to_string_pretty(&issues)
+            .map_err(|e| format!("Ошибка сериализации отчёта: {}", e))?,
+        "text" => issues
+            .iter()
+            .map(|issue| format!("{}:{}: {}: {}", issue.file, issue.line, issue.key, issue.message))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        format => return Err(format!("Неизвестный формат отчёта: {}", format)),
+    };
+    print_or_write(&output, args.out.as_deref())?;
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Нарушений глоссария: {}", issues.len()))
+    }
+}
+
+fn lang_export(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let path = args.path.clone().ok_or(USAGE)?;
+    let format = ExchangeFormat::parse(args.format.as_deref().ok_or(USAGE)?)?;
+
+    let units = export_units(&args.required_source_tree()?, &read_lang_tree(&translation_dir(&path))?)?;
+    let content = render(&units, format)?;
+    match &args.out {
+        Some(out) => {
+            write_atomic(out, &content)?;
+            println!("Экспортировано строк: {}", units.len());
+            Ok(())
+        }
+        None => print_or_write(&String::from_utf8_lossy(&content), None),
+    }
+}
+
+fn lang_import(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let path = args.path.clone().ok_or(USAGE)?;
+    let input = args.from.clone().ok_or(USAGE)?;
+    let format = match &args.format {
+        Some(format) => ExchangeFormat::parse(format)?,
+        None => ExchangeFormat::from_path(&input)?,
+    };
+
+    let bytes = fs::read(&input).map_err(|e| format!("Ошибка чтения {}: {}", input.display(), e))?;
+    let report = import_bytes(&bytes, format, &translation_dir(&path), &args.required_source_tree()?)?;
+    println!("Кодировка: {}", report.encoding);
+    println!(
+        "Обновлено: {}, без изменений: {}, без перевода: {}",
+        report.updated, report.unchanged, report.untranslated
+    );
+    for file in &report.files {
+        println!("Записан {}", file);
+    }
+    for conflict in &report.conflicts {
+        println!("{}: {}: {}", conflict.file, conflict.key, conflict.message);
+    }
+
+    if report.conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Конфликтов: {}", report.conflicts.len()))
+    }
+}
+
+fn lang_stale(args: &[String]) -> Result<(), String> {
+    let args = LangArgs::parse(args)?;
+    let path = args.path.clone().ok_or(USAGE)?;
+    let game = args.game_dir()?;
+
+    let hashes = SourceHashes::read(&path.join(SOURCE_HASHES_FILE))?;
+    let mut report = hashes.check(&game_source_tree(&game)?, &read_lang_tree(&translation_dir(&path))?);
+    if args.record || args.accept {
+        let accept = report
+            .stale
+            .iter()
+            .filter(|_| args.accept)
+            .map(|entry| accept_key(&entry.file, &entry.key))
+            .collect();
+        report = record_dir(&path, &game, &accept)?;
+    }
+
+    let output = match args.format.as_deref().unwrap_or("text") {
+        "json" => serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Ошибка сериализации отчёта: {}", e))?,
+        "text" => report
+            .stale
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{}:{}: {}{}\n  {}",
+                    entry.file,
+                    entry.line,
+                    entry.key,
+                    entry
+                        .since_build
+                        .as_deref()
+                        .map(|build| format!(" (изменено в {})", build))
+                        .unwrap_or_default(),
+                    entry.diff
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        format => return Err(format!("Неизвестный формат отчёта: {}", format)),
+    };
+    print_or_write(&output, args.out.as_deref())?;
+
+    if report.untracked > 0 {
+        eprintln!("Не отслеживается ключей: {} (запустите с --record)", report.untracked);
+    }
+    if report.stale.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Устаревших переводов: {}", report.stale.len()))
+    }
+}
+
+fn read_config<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Ошибка чтения конфигурации {}: {}", path.display(), e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Ошибка парсинга конфигурации {}: {}", path.display(), e))
+}
+
+fn print_or_write(content: &str, out: Option<&Path>) -> Result<(), String> {
+    match out {
+        Some(path) => write_atomic(path, content.as_bytes()),
+        None => {
+            println!("{}", content);
+            Ok(())
+        }
+    }
+}
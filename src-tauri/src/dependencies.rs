@@ -0,0 +1,241 @@
+use crate::manifest::PackManifest;
+use crate::pack_store::resolve_pack_dir_for;
+use crate::providers::{all_providers, provider_or_active};
+use crate::settings::{load_settings, save_settings};
+use crate::version::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AvailablePack {
+    pub id: String,
+    pub provider: String,
+    pub version: String,
+    pub disabled_by_default: bool,
+    pub enabled: bool,
+    #[serde(skip)]
+    pub dir: PathBuf,
+    #[serde(skip)]
+    pub manifest: PackManifest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlannedPack {
+    pub id: String,
+    pub provider: String,
+    pub version: String,
+    pub optional: bool,
+    #[serde(skip)]
+    pub dir: PathBuf,
+    #[serde(skip)]
+    pub manifest: PackManifest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DependencyPlan {
+    pub order: Vec<PlannedPack>,
+    pub conflicts: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+struct Resolver<'a> {
+    packs: &'a [AvailablePack],
+    plan: DependencyPlan,
+    visiting: Vec<String>,
+    constraints: BTreeMap<String, Vec<(String, String)>>,
+}
+
+#[tauri::command]
+pub fn list_packs() -> Result<Vec<AvailablePack>, String> {
+    available_packs()
+}
+
+#[tauri::command]
+pub fn set_pack_enabled(id: String, enabled: bool) -> Result<(), String> {
+    let mut settings = load_settings()?;
+    settings.enabled_packs.retain(|pack| pack != &id);
+    if enabled {
+        settings.enabled_packs.push(id);
+    }
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn get_install_plan(provider: Option<String>) -> Result<DependencyPlan, String> {
+    resolve_plan(&provider_or_active(provider)?.id)
+}
+
+pub fn available_packs() -> Result<Vec<AvailablePack>, String> {
+    let enabled = load_settings()?.enabled_packs;
+    let mut packs = Vec::new();
+
+    for provider in all_providers()? {
+        let Ok(dir) = resolve_pack_dir_for(&provider.id) else {
+            continue;
+        };
+        let Ok(manifest) = PackManifest::read_from(&dir) else {
+            continue;
+        };
+
+        let id = manifest.id().unwrap_or_else(|| provider.id.clone());
+        packs.push(AvailablePack {
+            enabled: !manifest.disabled_by_default || enabled.contains(&id),
+            disabled_by_default: manifest.disabled_by_default,
+            version: manifest.version.clone(),
+            provider: provider.id,
+            id,
+            dir,
+            manifest,
+        });
+    }
+
+    Ok(packs)
+}
+
+pub fn resolve_plan(provider: &str) -> Result<DependencyPlan, String> {
+    let packs = available_packs()?;
+    let root = packs
+        .iter()
+        .find(|pack| pack.provider == provider)
+        .ok_or("Пакет локализации не найден")?;
+
+    let mut resolver = Resolver {
+        packs: &packs,
+        plan: DependencyPlan::default(),
+        visiting: Vec::new(),
+        constraints: BTreeMap::new(),
+    };
+
+    if !root.enabled {
+        resolver.plan.conflicts.push(format!(
+            "Пакет {} отключён по умолчанию, включите его перед установкой",
+            root.id
+        ));
+    }
+
+    resolver.visit(root, false);
+    resolver.check_constraints();
+    Ok(resolver.plan)
+}
+
+impl Resolver<'_> {
+    fn visit(&mut self, pack: &AvailablePack, optional: bool) {
+        if self.plan.order.iter().any(|planned| planned.id == pack.id) {
+            return;
+        }
+
+        if let Some(position) = self.visiting.iter().position(|id| id == &pack.id) {
+            let mut cycle = self.visiting[position..].to_vec();
+            cycle.push(pack.id.clone());
+            self.plan
+                .conflicts
+                .push(format!("Циклическая зависимость: {}", cycle.join(" -> ")));
+            return;
+        }
+
+        self.visiting.push(pack.id.clone());
+        for (dependency, range) in &pack.manifest.dependencies {
+            self.require(&pack.id, dependency, range, false);
+        }
+        for (dependency, range) in &pack.manifest.optional_dependencies {
+            self.require(&pack.id, dependency, range, true);
+        }
+        self.visiting.pop();
+
+        self.plan.order.push(PlannedPack {
+            id: pack.id.clone(),
+            provider: pack.provider.clone(),
+            version: pack.version.clone(),
+            optional,
+            dir: pack.dir.clone(),
+            manifest: pack.manifest.clone(),
+        });
+    }
+
+    fn require(&mut self, requirer: &str, dependency: &str, range: &str, optional: bool) {
+        let requirement = match VersionReq::parse(range) {
+            Ok(requirement) => requirement,
+            Err(e) => {
+                self.plan
+                    .conflicts
+                    .push(format!("{}: некорректный диапазон версий для {}: {}", requirer, dependency, e));
+                return;
+            }
+        };
+        self.constraints
+            .entry(dependency.to_string())
+            .or_default()
+            .push((requirer.to_string(), range.to_string()));
+
+        let candidates: Vec<&AvailablePack> = self.packs.iter().filter(|pack| pack.id == dependency).collect();
+        let selected = candidates
+            .iter()
+            .filter_map(|pack| Some((Version::parse(&pack.version).ok()?, *pack)))
+            .filter(|(version, _)| requirement.matches(version))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, pack)| pack);
+
+        let Some(selected) = selected else {
+            let found: Vec<&str> = candidates.iter().map(|pack| pack.version.as_str()).collect();
+            let message = if found.is_empty() {
+                format!("{} требует пакет {} {}, но он не установлен", requirer, dependency, range)
+            } else {
+                format!(
+                    "{} требует пакет {} {}, доступны версии: {}",
+                    requirer,
+                    dependency,
+                    range,
+                    found.join(", ")
+                )
+            };
+            if optional {
+                self.plan.skipped.push(message);
+            } else {
+                self.plan.conflicts.push(message);
+            }
+            return;
+        };
+
+        if !selected.enabled {
+            if optional {
+                self.plan
+                    .skipped
+                    .push(format!("Пакет {} отключён по умолчанию", selected.id));
+                return;
+            }
+            self.plan.conflicts.push(format!(
+                "{} требует пакет {}, но он отключён по умолчанию, включите его перед установкой",
+                requirer, selected.id
+            ));
+        }
+
+        self.visit(selected, optional);
+    }
+
+    fn check_constraints(&mut self) {
+        for pack in &self.plan.order {
+            let Some(requirements) = self.constraints.get(&pack.id) else {
+                continue;
+            };
+            let Ok(version) = Version::parse(&pack.version) else {
+                continue;
+            };
+
+            let unmet: Vec<String> = requirements
+                .iter()
+                .filter(|(_, range)| VersionReq::parse(range).is_ok_and(|req| !req.matches(&version)))
+                .map(|(requirer, range)| format!("{} требует {}", requirer, range))
+                .collect();
+
+            if !unmet.is_empty() {
+                self.plan.conflicts.push(format!(
+                    "Несовместимые требования к пакету {} (выбрана версия {}): {}",
+                    pack.id,
+                    pack.version,
+                    unmet.join(", ")
+                ));
+            }
+        }
+    }
+}
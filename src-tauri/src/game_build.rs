@@ -0,0 +1,129 @@
+use crate::gamepath::hytale_game_dir;
+use crate::manifest::PackManifest;
+use crate::pack_store::resolve_pack_dir_for;
+use crate::providers::provider_for_game;
+use crate::settings::{load_settings, CompatibilityPolicy};
+use crate::version::Version;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+const SERVER_JAR: &str = "Server/HytaleServer.jar";
+const BUILD_FILES: [&str; 3] = ["version.txt", "build.txt", "Client/version.txt"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Compatibility {
+    Compatible,
+    Incompatible,
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CompatibilityReport {
+    pub game_build: Option<String>,
+    pub pack_version: String,
+    pub supported_builds: String,
+    pub compatibility: Compatibility,
+    pub blocked: bool,
+}
+
+#[tauri::command]
+pub fn get_game_build() -> Result<Option<String>, String> {
+    let game = hytale_game_dir()?;
+    Ok(detect_game_build(&game))
+}
+
+#[tauri::command]
+pub fn check_pack_compatibility() -> Result<CompatibilityReport, String> {
+    let game = hytale_game_dir()?;
+    let manifest = PackManifest::read_from(&resolve_pack_dir_for(&provider_for_game(&game)?.id)?)?;
+    compatibility_report(&manifest, &game)
+}
+
+pub fn compatibility_report(manifest: &PackManifest, game: &Path) -> Result<CompatibilityReport, String> {
+    let game_build = detect_game_build(game);
+    let compatibility = check_compatibility(manifest, game_build.as_deref())?;
+    let policy = load_settings()?.compatibility_policy;
+
+    Ok(CompatibilityReport {
+        game_build,
+        pack_version: manifest.version.clone(),
+        supported_builds: manifest.supported_builds()?.to_string(),
+        compatibility,
+        blocked: policy == CompatibilityPolicy::Block && compatibility == Compatibility::Incompatible,
+    })
+}
+
+pub fn ensure_compatible(manifest: &PackManifest, game: &Path) -> Result<Option<String>, String> {
+    let report = compatibility_report(manifest, game)?;
+
+    if report.compatibility == Compatibility::Incompatible {
+        let message = format!(
+            "Локализация {} рассчитана на сборки игры {}, установлена сборка {}",
+            report.pack_version,
+            report.supported_builds,
+            report.game_build.as_deref().unwrap_or("неизвестна")
+        );
+        if report.blocked {
+            return Err(message);
+        }
+        return Ok(Some(message));
+    }
+
+    Ok(None)
+}
+
+pub fn check_compatibility(manifest: &PackManifest, game_build: Option<&str>) -> Result<Compatibility, String> {
+    let supported = manifest.supported_builds()?;
+    let build = match game_build.map(Version::parse_game_build) {
+        Some(Ok(build)) => build,
+        _ => return Ok(Compatibility::Unknown),
+    };
+
+    if supported.matches(&build) {
+        Ok(Compatibility::Compatible)
+    } else {
+        Ok(Compatibility::Incompatible)
+    }
+}
+
+pub fn detect_game_build(game: &Path) -> Option<String> {
+    read_jar_version(&game.join(SERVER_JAR))
+        .or_else(|| {
+            BUILD_FILES.iter().find_map(|name| {
+                fs::read_to_string(game.join(name))
+                    .ok()
+                    .map(|content| content.trim().to_string())
+                    .filter(|content| !content.is_empty() && !content.contains('\n'))
+            })
+        })
+        .or_else(|| {
+            let resolved = fs::canonicalize(game).ok()?;
+            let name = resolved.file_name()?.to_string_lossy().to_string();
+            (name != "latest").then_some(name)
+        })
+}
+
+fn read_jar_version(jar: &Path) -> Option<String> {
+    let file = fs::File::open(jar).ok()?;
+    let mut archive = zip::ZipArchive::new(file).ok()?;
+    let mut entry = archive.by_name("META-INF/MANIFEST.MF").ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).ok()?;
+
+    let mut unfolded: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix(' '), unfolded.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => unfolded.push(line.to_string()),
+        }
+    }
+
+    unfolded.iter().find_map(|line| {
+        line.strip_prefix("Implementation-Version:")
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    })
+}
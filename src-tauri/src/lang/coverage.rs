@@ -0,0 +1,217 @@
+use super::{game_source_tree, pack_lang_tree, LangFile};
+use crate::game_build::detect_game_build;
+use crate::gamepath::hytale_game_dir;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileCoverage {
+    pub file: String,
+    pub total: usize,
+    pub translated: usize,
+    pub percent: f64,
+    pub missing: Vec<String>,
+    pub obsolete: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CoverageReport {
+    pub game_build: Option<String>,
+    pub total: usize,
+    pub translated: usize,
+    pub percent: f64,
+    pub files: Vec<FileCoverage>,
+}
+
+#[tauri::command]
+pub fn get_translation_coverage() -> Result<CoverageReport, String> {
+    game_coverage(&hytale_game_dir()?)
+}
+
+#[tauri::command]
+pub fn export_translation_coverage(format: String) -> Result<String, String> {
+    get_translation_coverage()?.render(&format)
+}
+
+pub fn game_coverage(game: &Path) -> Result<CoverageReport, String> {
+    let mut report = compare_trees(&game_source_tree(game)?, &pack_lang_tree(game)?);
+    report.game_build = detect_game_build(game);
+    Ok(report)
+}
+
+pub fn compare_trees(source: &BTreeMap<String, LangFile>, target: &BTreeMap<String, LangFile>) -> CoverageReport {
+    let empty = LangFile::default();
+    let names: BTreeSet<&String> = source.keys().chain(target.keys()).collect();
+
+    let files: Vec<FileCoverage> = names
+        .into_iter()
+        .map(|name| {
+            compare_file(
+                name,
+                source.get(name).unwrap_or(&empty),
+                target.get(name).unwrap_or(&empty),
+            )
+        })
+        .collect();
+
+    let total = files.iter().map(|file| file.total).sum();
+    let translated = files.iter().map(|file| file.translated).sum();
+    CoverageReport {
+        game_build: None,
+        total,
+        translated,
+        percent: percent(translated, total),
+        files,
+    }
+}
+
+fn compare_file(name: &str, source: &LangFile, target: &LangFile) -> FileCoverage {
+    let source_keys = unique_keys(source);
+    let target_keys = unique_keys(target);
+    let source_set: BTreeSet<&str> = source_keys.iter().copied().collect();
+    let target_set: BTreeSet<&str> = target_keys.iter().copied().collect();
+
+    let missing: Vec<String> = source_keys
+        .iter()
+        .filter(|key| !target_set.contains(*key))
+        .map(|key| key.to_string())
+        .collect();
+    let obsolete: Vec<String> = target_keys
+        .iter()
+        .filter(|key| !source_set.contains(*key))
+        .map(|key| key.to_string())
+        .collect();
+
+    let total = source_keys.len();
+    let translated = total - missing.len();
+    FileCoverage {
+        file: name.to_string(),
+        total,
+        translated,
+        percent: percent(translated, total),
+        missing,
+        obsolete,
+    }
+}
+
+fn unique_keys(lang: &LangFile) -> Vec<&str> {
+    let mut seen = BTreeSet::new();
+    lang.entries()
+        .map(|entry| entry.key.as_str())
+        .filter(|key| seen.insert(*key))
+        .collect()
+}
+
+fn percent(translated: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 100.0;
+    }
+    (translated as f64 * 1000.0 / total as f64).round() / 10.0
+}
+
+impl CoverageReport {
+    pub fn render(&self, format: &str) -> Result<String, String> {
+        match format {
+            "json" => serde_json::to_string_pretty(self)
+                .map_err(|e| format!("Ошибка сериализации отчёта: {}", e)),
+            "md" | "markdown" => Ok(self.to_markdown()),
+            _ => Err(format!("Неизвестный формат отчёта: {}", format)),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Покрытие перевода\n\n");
+        if let Some(build) = &self.game_build {
+            let _ = writeln!(out, "Сборка игры: {}\n", build);
+        }
+        let _ = writeln!(
+            out,
+            "Переведено {} из {} ключей ({:.1}%)\n",
+            self.translated, self.total, self.percent
+        );
+
+        out.push_str("| Файл | Переведено | Всего | Покрытие | Отсутствует | Устарело |\n");
+        out.push_str("|---|---:|---:|---:|---:|---:|\n");
+        for file in &self.files {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {:.1}% | {} | {} |",
+                file.file,
+                file.translated,
+                file.total,
+                file.percent,
+                file.missing.len(),
+                file.obsolete.len()
+            );
+        }
+
+        for file in self.files.iter().filter(|file| !file.missing.is_empty() || !file.obsolete.is_empty()) {
+            let _ = writeln!(out, "\n## {}", file.file);
+            for (title, keys) in [("Отсутствующие ключи", &file.missing), ("Устаревшие ключи", &file.obsolete)] {
+                if keys.is_empty() {
+                    continue;
+                }
+                let _ = writeln!(out, "\n### {}\n", title);
+                for key in keys {
+                    let _ = writeln!(out, "- `{}`", key);
+                }
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(files: &[(&str, &str)]) -> BTreeMap<String, LangFile> {
+        files
+            .iter()
+            .map(|(name, content)| (name.to_string(), LangFile::parse(content)))
+            .collect()
+    }
+
+    #[test]
+    fn counts_missing_and_obsolete_keys() {
+        let source = tree(&[("client.lang", "a = A\nb = B\nc = C\nc = C2\n"), ("server.lang", "x = X\n")]);
+        let target = tree(&[("client.lang", "a = А\nold = Старое\na = А2\n"), ("extra.lang", "y = Y\n")]);
+        let report = compare_trees(&source, &target);
+
+        let client = &report.files[0];
+        assert_eq!(client.file, "client.lang");
+        assert_eq!((client.total, client.translated), (3, 1));
+        assert_eq!(client.missing, vec!["b", "c"]);
+        assert_eq!(client.obsolete, vec!["old"]);
+        assert_eq!(client.percent, 33.3);
+
+        let extra = &report.files[1];
+        assert_eq!((extra.total, extra.translated, extra.percent), (0, 0, 100.0));
+        assert_eq!(extra.obsolete, vec!["y"]);
+
+        let server = &report.files[2];
+        assert_eq!((server.translated, server.percent), (0, 0.0));
+        assert_eq!((report.total, report.translated, report.percent), (4, 1, 25.0));
+    }
+
+    #[test]
+    fn empty_total_is_fully_covered() {
+        let report = compare_trees(&BTreeMap::new(), &BTreeMap::new());
+        assert_eq!((report.total, report.percent), (0, 100.0));
+        assert_eq!(percent(0, 0), 100.0);
+        assert_eq!(percent(2, 3), 66.7);
+    }
+
+    #[test]
+    fn renders_markdown_and_json() {
+        let report = compare_trees(&tree(&[("client.lang", "a = A\nb = B\n")]), &tree(&[("client.lang", "a = А\n")]));
+        let markdown = report.render("md").unwrap();
+        assert!(markdown.contains("| client.lang | 1 | 2 | 50.0% | 1 | 0 |"), "{}", markdown);
+        assert!(markdown.contains("- `b`"));
+        assert!(report.render("json").unwrap().contains("\"missing\""));
+        assert!(report.render("html").is_err());
+    }
+}
@@ -0,0 +1,774 @@
+use super::lint::translation_dir;
+use super::{game_source_tree, read_lang_tree, LangFile, LangItem, SOURCE_LOCALE};
+use crate::gamepath::hytale_game_dir;
+use crate::manifest::PACK_LOCALE;
+use encoding_rs::{Encoding, KOI8_R, UTF_8, WINDOWS_1251};
+use hytaleru_lib::write_atomic;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const CONTEXT_SEPARATOR: char = ':';
+const SECTION_PREFIX: &str = "Раздел: ";
+const CSV_HEADER: [&str; 5] = ["file", "key", "context", "source", "target"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExchangeFormat {
+    Po,
+    Xliff,
+    Csv,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExchangeUnit {
+    pub file: String,
+    pub key: String,
+    pub context: Vec<String>,
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictKind {
+    Duplicate,
+    UnknownKey,
+    SourceChanged,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportConflict {
+    pub file: String,
+    pub key: String,
+    pub kind: ConflictKind,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ImportReport {
+    pub encoding: String,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub untranslated: usize,
+    pub files: Vec<String>,
+    pub conflicts: Vec<ImportConflict>,
+}
+
+#[tauri::command]
+pub fn export_translation(path: String, format: String, out: String) -> Result<usize, String> {
+    let format = ExchangeFormat::parse(&format)?;
+    let source = game_source_tree(&hytale_game_dir()?)?;
+    let units = export_units(&source, &read_lang_tree(&translation_dir(Path::new(&path)))?)?;
+    write_atomic(Path::new(&out), &render(&units, format)?)?;
+    Ok(units.len())
+}
+
+#[tauri::command]
+pub fn import_translation(path: String, input: String, format: Option<String>) -> Result<ImportReport, String> {
+    let input = PathBuf::from(input);
+    let format = match format {
+        Some(format) => ExchangeFormat::parse(&format)?,
+        None => ExchangeFormat::from_path(&input)?,
+    };
+    let bytes = fs::read(&input).map_err(|e| format!("Ошибка чтения {}: {}", input.display(), e))?;
+    import_bytes(&bytes, format, &translation_dir(Path::new(&path)), &game_source_tree(&hytale_game_dir()?)?)
+}
+
+impl ExchangeFormat {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "po" | "pot" | "gettext" => Ok(ExchangeFormat::Po),
+            "xliff" | "xlf" => Ok(ExchangeFormat::Xliff),
+            "csv" => Ok(ExchangeFormat::Csv),
+            _ => Err(format!("Неизвестный формат обмена: {}", name)),
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .ok_or(format!("Не удалось определить формат файла {}", path.display()))?;
+        Self::parse(extension)
+    }
+}
+
+pub fn export_units(
+    source: &BTreeMap<String, LangFile>,
+    target: &BTreeMap<String, LangFile>,
+) -> Result<Vec<ExchangeUnit>, String> {
+    if source.is_empty() {
+        return Err(format!("Не найдены исходные строки {}", SOURCE_LOCALE));
+    }
+
+    let mut units = Vec::new();
+    for (file, original) in source {
+        let translated = target.get(file);
+        let source_context = contexts(original);
+        let target_context = translated.map(contexts).unwrap_or_default();
+        let mut seen = BTreeSet::new();
+
+        for entry in original.entries() {
+            if !seen.insert(entry.key.as_str()) {
+                continue;
+            }
+            let entry = original.get(&entry.key).unwrap_or(entry);
+            if entry.value.is_empty() {
+                continue;
+            }
+            let context = target_context
+                .get(&entry.key)
+                .filter(|context| !context.is_empty())
+                .or_else(|| source_context.get(&entry.key))
+                .cloned()
+                .unwrap_or_default();
+
+            units.push(ExchangeUnit {
+                file: file.clone(),
+                key: entry.key.clone(),
+                context,
+                source: entry.value.clone(),
+                target: translated
+                    .and_then(|lang| lang.get(&entry.key))
+                    .map(|entry| entry.value.clone())
+                    .unwrap_or_default(),
+            });
+        }
+    }
+    Ok(units)
+}
+
+fn contexts(lang: &LangFile) -> BTreeMap<String, Vec<String>> {
+    let mut contexts = BTreeMap::new();
+    let mut section = None;
+    let mut comments = Vec::new();
+
+    for item in &lang.items {
+        match item {
+            LangItem::Section { name, .. } => {
+                section = Some(name.clone());
+                comments.clear();
+            }
+            LangItem::Comment(raw) => comments.push(raw.trim().trim_start_matches('#').trim().to_string()),
+            LangItem::Entry(entry) => {
+                let mut context: Vec<String> = section.iter().map(|name| format!("{}{}", SECTION_PREFIX, name)).collect();
+                context.append(&mut comments);
+                contexts.insert(entry.key.clone(), context);
+            }
+            LangItem::Blank(_) | LangItem::Invalid(_) => {}
+        }
+    }
+    contexts
+}
+
+pub fn render(units: &[ExchangeUnit], format: ExchangeFormat) -> Result<Vec<u8>, String> {
+    match format {
+        ExchangeFormat::Po => Ok(render_po(units).into_bytes()),
+        ExchangeFormat::Xliff => Ok(render_xliff(units).into_bytes()),
+        ExchangeFormat::Csv => render_csv(units),
+    }
+}
+
+pub fn parse(content: &str, format: ExchangeFormat) -> Result<Vec<ExchangeUnit>, String> {
+    match format {
+        ExchangeFormat::Po => parse_po(content),
+        ExchangeFormat::Xliff => parse_xliff(content),
+        ExchangeFormat::Csv => parse_csv(content),
+    }
+}
+
+pub fn import_bytes(
+    bytes: &[u8],
+    format: ExchangeFormat,
+    dir: &Path,
+    source: &BTreeMap<String, LangFile>,
+) -> Result<ImportReport, String> {
+    let (content, encoding) = decode(bytes, format);
+    let mut report = import_units(parse(&content, format)?, dir, source)?;
+    report.encoding = encoding.to_string();
+    Ok(report)
+}
+
+pub fn import_units(
+    units: Vec<ExchangeUnit>,
+    dir: &Path,
+    source: &BTreeMap<String, LangFile>,
+) -> Result<ImportReport, String> {
+    let mut report = ImportReport::default();
+    let mut grouped: BTreeMap<String, BTreeMap<String, Vec<ExchangeUnit>>> = BTreeMap::new();
+    for unit in units {
+        if unit.target.is_empty() {
+            report.untranslated += 1;
+            continue;
+        }
+        grouped
+            .entry(unit.file.clone())
+            .or_default()
+            .entry(unit.key.clone())
+            .or_default()
+            .push(unit);
+    }
+
+    for (file, keys) in grouped {
+        let original = source.get(&file);
+        let mut accepted = Vec::new();
+
+        for (key, variants) in keys {
+            let mut conflict = |kind, message| {
+                report.conflicts.push(ImportConflict {
+                    file: file.clone(),
+                    key: key.clone(),
+                    kind,
+                    message,
+                })
+            };
+
+            let targets: BTreeSet<&str> = variants.iter().map(|unit| unit.target.as_str()).collect();
+            if targets.len() > 1 {
+                conflict(
+                    ConflictKind::Duplicate,
+                    format!("ключ встречается {} раз с разными переводами, пропущен", variants.len()),
+                );
+                continue;
+            }
+
+            let unit = &variants[0];
+            let current = original.and_then(|lang| lang.get(&key));
+            if !is_lang_path(&file) || (!source.is_empty() && current.is_none()) {
+                conflict(
+                    ConflictKind::UnknownKey,
+                    format!("ключа нет в {}, пропущен", SOURCE_LOCALE),
+                );
+                continue;
+            }
+            if let Some(current) = current.filter(|current| !unit.source.is_empty() && current.value != unit.source) {
+                conflict(
+                    ConflictKind::SourceChanged,
+                    format!("перевод сделан для текста {:?}, сейчас {:?}", unit.source, current.value),
+                );
+            }
+            accepted.push((key, unit.target.clone()));
+        }
+
+        if accepted.is_empty() {
+            continue;
+        }
+
+        let order: BTreeMap<&str, usize> = original
+            .map(|lang| lang.entries().enumerate().map(|(index, entry)| (entry.key.as_str(), index)).collect())
+            .unwrap_or_default();
+        accepted.sort_by_key(|(key, _)| order.get(key.as_str()).copied().unwrap_or(usize::MAX));
+
+        let path = dir.join(&file);
+        let mut lang = if path.exists() {
+            LangFile::read(&path)?
+        } else {
+            LangFile::default()
+        };
+
+        let mut changed = false;
+        for (key, value) in accepted {
+            if lang.get(&key).is_some_and(|entry| entry.value == value) {
+                report.unchanged += 1;
+                continue;
+            }
+
+            let mut found = false;
+            for item in &mut lang.items {
+                if let LangItem::Entry(entry) = item {
+                    if entry.key == key {
+                        entry.set_value(&value);
+                        found = true;
+                    }
+                }
+            }
+            if !found {
+                lang.set(&key, &value);
+            }
+            report.updated += 1;
+            changed = true;
+        }
+
+        if changed {
+            lang.write(&path)?;
+            report.files.push(file);
+        }
+    }
+
+    Ok(report)
+}
+
+pub fn decode(bytes: &[u8], format: ExchangeFormat) -> (String, &'static str) {
+    if let Some((encoding, length)) = Encoding::for_bom(bytes) {
+        let (text, _) = encoding.decode_without_bom_handling(&bytes[length..]);
+        return (text.replace("\r\n", "\n"), encoding.name());
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return (text.replace("\r\n", "\n"), UTF_8.name());
+    }
+
+    let declared = declared_charset(bytes, format)
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .filter(|encoding| *encoding != UTF_8);
+    let encoding = declared.unwrap_or_else(|| {
+        let score = |encoding: &'static Encoding| {
+            let (text, _) = encoding.decode_without_bom_handling(bytes);
+            text.chars().filter(|c| matches!(c, 'а'..='я' | 'ё')).count()
+        };
+        if score(KOI8_R) > score(WINDOWS_1251) {
+            KOI8_R
+        } else {
+            WINDOWS_1251
+        }
+    });
+
+    let (text, _) = encoding.decode_without_bom_handling(bytes);
+    (text.replace("\r\n", "\n"), encoding.name())
+}
+
+fn declared_charset(bytes: &[u8], format: ExchangeFormat) -> Option<String> {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(2048)]).to_lowercase();
+    let marker = match format {
+        ExchangeFormat::Po => "charset=",
+        ExchangeFormat::Xliff => "encoding=",
+        ExchangeFormat::Csv => return None,
+    };
+    let start = head.find(marker)? + marker.len();
+    let value: String = head[start..]
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    (!value.is_empty()).then_some(value)
+}
+
+fn is_lang_path(file: &str) -> bool {
+    file.ends_with(".lang") && Path::new(file).components().all(|part| matches!(part, Component::Normal(_)))
+}
+
+fn split_context(context: &str) -> (String, String) {
+    match context.split_once(CONTEXT_SEPARATOR) {
+        Some((file, key)) if file.ends_with(".lang") => (file.to_string(), key.to_string()),
+        _ => (String::new(), context.to_string()),
+    }
+}
+
+fn render_po(units: &[ExchangeUnit]) -> String {
+    let mut po = String::new();
+    po.push_str("msgid \"\"\nmsgstr \"\"\n");
+    for header in [
+        "Language: ru\\n".to_string(),
+        "MIME-Version: 1.0\\n".to_string(),
+        "Content-Type: text/plain; charset=UTF-8\\n".to_string(),
+        "Content-Transfer-Encoding: 8bit\\n".to_string(),
+        format!("X-Source-Language: {}\\n", SOURCE_LOCALE),
+        format!("X-Target-Language: {}\\n", PACK_LOCALE),
+    ] {
+        po.push_str(&format!("\"{}\"\n", header));
+    }
+
+    for unit in units {
+        po.push('\n');
+        for line in unit.context.iter().flat_map(|context| context.lines()) {
+            po.push_str(&format!("#. {}\n", line));
+        }
+        po.push_str(&format!("#: {}\n", unit.file));
+        po.push_str(&po_field("msgctxt", &format!("{}{}{}", unit.file, CONTEXT_SEPARATOR, unit.key)));
+        po.push_str(&po_field("msgid", &unit.source));
+        po.push_str(&po_field("msgstr", &unit.target));
+    }
+    po
+}
+
+fn po_field(keyword: &str, value: &str) -> String {
+    let lines: Vec<&str> = value.split_inclusive('\n').collect();
+    if lines.len() < 2 {
+        return format!("{} \"{}\"\n", keyword, po_escape(value));
+    }
+
+    let mut field = format!("{} \"\"\n", keyword);
+    for line in lines {
+        field.push_str(&format!("\"{}\"\n", po_escape(line)));
+    }
+    field
+}
+
+fn po_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn po_unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[derive(Default)]
+struct PoEntry {
+    context: Vec<String>,
+    msgctxt: Option<String>,
+    msgid: Option<String>,
+    msgstr: Option<String>,
+    fuzzy: bool,
+    obsolete: bool,
+}
+
+fn parse_po(content: &str) -> Result<Vec<ExchangeUnit>, String> {
+    let mut units = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut current = "";
+
+    let flush = |entry: &mut PoEntry, units: &mut Vec<ExchangeUnit>| {
+        let finished = std::mem::take(entry);
+        let (Some(msgid), Some(context)) = (finished.msgid, finished.msgctxt) else {
+            return;
+        };
+        if finished.obsolete {
+            return;
+        }
+        let (file, key) = split_context(&context);
+        units.push(ExchangeUnit {
+            file,
+            key,
+            context: finished.context,
+            source: msgid,
+            target: if finished.fuzzy {
+                String::new()
+            } else {
+                finished.msgstr.unwrap_or_default()
+            },
+        });
+    };
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            flush(&mut entry, &mut units);
+            current = "";
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            if entry.msgstr.is_some() {
+                flush(&mut entry, &mut units);
+            }
+            match comment.chars().next() {
+                Some('.') => entry.context.push(comment[1..].trim().to_string()),
+                Some(',') => entry.fuzzy |= comment.split(',').any(|flag| flag.trim() == "fuzzy"),
+                Some('~') => entry.obsolete = true,
+                _ => {}
+            }
+            current = "";
+            continue;
+        }
+
+        let (keyword, rest) = match line.find(char::is_whitespace) {
+            Some(split) if !line.starts_with('"') => (&line[..split], line[split..].trim()),
+            _ => ("", line),
+        };
+        let value = rest
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .ok_or(format!("Ошибка в PO, строка {}: ожидалась строка в кавычках", index + 1))?;
+        let value = po_unescape(value);
+
+        if matches!(keyword, "msgctxt" | "msgid") && entry.msgstr.is_some() {
+            flush(&mut entry, &mut units);
+        }
+        if !keyword.is_empty() {
+            current = match keyword {
+                "msgctxt" => "msgctxt",
+                "msgid" => "msgid",
+                "msgstr" | "msgstr[0]" => "msgstr",
+                _ => "",
+            };
+        }
+
+        let field = match current {
+            "msgctxt" => Some(&mut entry.msgctxt),
+            "msgid" => Some(&mut entry.msgid),
+            "msgstr" => Some(&mut entry.msgstr),
+            _ => None,
+        };
+        if let Some(field) = field {
+            if keyword.is_empty() {
+                field.get_or_insert_with(String::new).push_str(&value);
+            } else {
+                *field = Some(value);
+            }
+        }
+    }
+    flush(&mut entry, &mut units);
+
+    Ok(units)
+}
+
+fn render_xliff(units: &[ExchangeUnit]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" srcLang=\"{}\" trgLang=\"{}\">\n",
+        SOURCE_LOCALE, PACK_LOCALE
+    ));
+
+    let mut file: Option<&str> = None;
+    for unit in units {
+        if file != Some(unit.file.as_str()) {
+            if file.is_some() {
+                xml.push_str("  </file>\n");
+            }
+            xml.push_str(&format!(
+                "  <file id=\"{}\" original=\"{}\">\n",
+                xml_escape(&unit.file),
+                xml_escape(&unit.file)
+            ));
+            file = Some(&unit.file);
+        }
+
+        xml.push_str(&format!("    <unit id=\"{}\">\n", xml_escape(&unit.key)));
+        if !unit.context.is_empty() {
+            xml.push_str("      <notes>\n");
+            for context in &unit.context {
+                match context.strip_prefix(SECTION_PREFIX) {
+                    Some(section) => xml.push_str(&format!(
+                        "        <note category=\"section\">{}</note>\n",
+                        xml_escape(section)
+                    )),
+                    None => xml.push_str(&format!("        <note>{}</note>\n", xml_escape(context))),
+                }
+            }
+            xml.push_str("      </notes>\n");
+        }
+
+        let state = if unit.target.is_empty() { "initial" } else { "translated" };
+        xml.push_str(&format!("      <segment state=\"{}\">\n", state));
+        xml.push_str(&format!("        <source>{}</source>\n", xml_escape(&unit.source)));
+        if !unit.target.is_empty() {
+            xml.push_str(&format!("        <target>{}</target>\n", xml_escape(&unit.target)));
+        }
+        xml.push_str("      </segment>\n");
+        xml.push_str("    </unit>\n");
+    }
+    if file.is_some() {
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</xliff>\n");
+    xml
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\r' => escaped.push_str("&#13;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn parse_xliff(content: &str) -> Result<Vec<ExchangeUnit>, String> {
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: false,
+        ..Default::default()
+    };
+    let document = roxmltree::Document::parse_with_options(content, options)
+        .map_err(|e| format!("Ошибка парсинга XLIFF: {}", e))?;
+
+    let text = |node: roxmltree::Node| -> String {
+        node.descendants()
+            .filter(|child| child.is_text())
+            .filter_map(|child| child.text())
+            .collect()
+    };
+
+    let mut units = Vec::new();
+    for file in document.descendants().filter(|node| node.has_tag_name("file")) {
+        let name = file.attribute("original").or(file.attribute("id")).unwrap_or_default();
+
+        for unit in file
+            .descendants()
+            .filter(|node| node.has_tag_name("unit") || node.has_tag_name("trans-unit"))
+        {
+            let Some(id) = unit.attribute("resname").or(unit.attribute("id")) else {
+                continue;
+            };
+            let (file, key) = if name.ends_with(".lang") {
+                (name.to_string(), id.to_string())
+            } else {
+                split_context(id)
+            };
+
+            let mut parsed = ExchangeUnit {
+                file,
+                key,
+                ..Default::default()
+            };
+            for child in unit.descendants().filter(|node| node.is_element()) {
+                match child.tag_name().name() {
+                    "note" => parsed.context.push(match child.attribute("category") {
+                        Some("section") => format!("{}{}", SECTION_PREFIX, text(child)),
+                        _ => text(child),
+                    }),
+                    "source" => parsed.source.push_str(&text(child)),
+                    "target" => parsed.target.push_str(&text(child)),
+                    _ => {}
+                }
+            }
+
+            let initial = unit
+                .descendants()
+                .find(|node| node.has_tag_name("segment"))
+                .and_then(|segment| segment.attribute("state"))
+                == Some("initial");
+            if initial {
+                parsed.target.clear();
+            }
+            units.push(parsed);
+        }
+    }
+    Ok(units)
+}
+
+fn render_csv(units: &[ExchangeUnit]) -> Result<Vec<u8>, String> {
+    let mut writer = csv::Writer::from_writer("\u{feff}".as_bytes().to_vec());
+    let error = |e: csv::Error| format!("Ошибка записи CSV: {}", e);
+
+    writer.write_record(CSV_HEADER).map_err(error)?;
+    for unit in units {
+        writer
+            .write_record([
+                unit.file.as_str(),
+                unit.key.as_str(),
+                unit.context.join("\n").as_str(),
+                unit.source.as_str(),
+                unit.target.as_str(),
+            ])
+            .map_err(error)?;
+    }
+    writer.into_inner().map_err(|e| format!("Ошибка записи CSV: {}", e))
+}
+
+fn parse_csv(content: &str) -> Result<Vec<ExchangeUnit>, String> {
+    let header = content.lines().next().unwrap_or_default();
+    let delimiter = if header.matches(';').count() > header.matches(',').count() {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Ошибка чтения CSV: {}", e))?
+        .iter()
+        .map(|header| header.trim().to_lowercase())
+        .collect();
+    let column = |name: &str| headers.iter().position(|header| header == name);
+    let (Some(file), Some(key), Some(target)) = (column("file"), column("key"), column("target")) else {
+        return Err("В CSV нужны колонки file, key и target".to_string());
+    };
+    let (context, source) = (column("context"), column("source"));
+
+    let mut units = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| format!("Ошибка чтения CSV: {}", e))?;
+        let field = |index: Option<usize>| index.and_then(|index| record.get(index)).unwrap_or_default().to_string();
+        units.push(ExchangeUnit {
+            file: field(Some(file)),
+            key: field(Some(key)),
+            context: field(context).lines().map(str::to_string).collect(),
+            source: field(source),
+            target: field(Some(target)),
+        });
+    }
+    Ok(units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(content: &str) -> BTreeMap<String, LangFile> {
+        BTreeMap::from([("client.lang".to_string(), LangFile::parse(content))])
+    }
+
+    fn sample_units() -> Vec<ExchangeUnit> {
+        let source = tree("# === Items ===\na = Sword \"x\"\nb = Two\\nlines\nc = Hello {name}\nd = <b>Bold</b> & more\nempty =\n");
+        let target = tree("# === Предметы ===\na = Меч «x»\nb = Две\\nстроки\n");
+        export_units(&source, &target).unwrap()
+    }
+
+    #[test]
+    fn formats_round_trip() {
+        let units = sample_units();
+        for format in [ExchangeFormat::Po, ExchangeFormat::Xliff, ExchangeFormat::Csv] {
+            let bytes = render(&units, format).unwrap();
+            let (text, encoding) = decode(&bytes, format);
+            assert_eq!(encoding, "UTF-8");
+            assert_eq!(parse(&text, format).unwrap(), units, "{:?}\n{}", format, text);
+        }
+    }
+
+    #[test]
+    fn po_parses_multiline_and_skips_fuzzy() {
+        let po = "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n\n#, fuzzy\nmsgctxt \"client.lang:c\"\nmsgid \"Hello {name}\"\nmsgstr \"Привет\"\n\nmsgctxt \"client.lang:b\"\nmsgid \"\"\n\"Two\\n\"\n\"lines\"\nmsgstr \"\"\n\"Две\\n\"\n\"строчки\"\n";
+        let units = parse(po, ExchangeFormat::Po).unwrap();
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].target, "");
+        assert_eq!(units[1].target, "Две\nстрочки");
+    }
+
+    #[test]
+    fn decode_detects_legacy_encodings() {
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode("file;key;target\r\nclient.lang;a;Привет, как дела\r\n");
+        assert_eq!(decode(&bytes, ExchangeFormat::Csv).1, "windows-1251");
+        let (bytes, _, _) = encoding_rs::KOI8_R.encode("file,key,target\nclient.lang,a,Привет мир как дела\n");
+        assert_eq!(decode(&bytes, ExchangeFormat::Csv).1, "KOI8-R");
+    }
+
+    #[test]
+    fn export_skips_empty_sources() {
+        let keys: Vec<String> = sample_units().into_iter().map(|unit| unit.key).collect();
+        assert_eq!(keys, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn xliff_rejects_doctype() {
+        let xliff = "<?xml version=\"1.0\"?><!DOCTYPE xliff [<!ENTITY x \"y\">]><xliff version=\"1.2\"><file original=\"client.lang\"><body><trans-unit id=\"a\"><source>&x;</source><target>z</target></trans-unit></body></file></xliff>";
+        assert!(parse(xliff, ExchangeFormat::Xliff).is_err());
+    }
+}
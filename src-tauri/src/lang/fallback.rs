@@ -0,0 +1,111 @@
+use super::{read_lang_tree, LangFile, LangItem};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+pub const FALLBACK_SECTION: &str = "Не переведено (en-US)";
+
+pub fn fill_missing_keys(source_dir: &Path, target_dir: &Path) -> Result<usize, String> {
+    let mut filled = 0;
+
+    for (file, source) in read_lang_tree(source_dir)? {
+        let path = target_dir.join(&file);
+        let mut target = if path.exists() {
+            LangFile::read(&path)?
+        } else {
+            LangFile::default()
+        };
+
+        let mut present: BTreeSet<String> = target.entries().map(|entry| entry.key.clone()).collect();
+        let missing: Vec<_> = source
+            .entries()
+            .filter(|entry| present.insert(entry.key.clone()))
+            .cloned()
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        filled += missing.len();
+        let section = target
+            .items
+            .iter()
+            .position(|item| matches!(item, LangItem::Section { name, .. } if name == FALLBACK_SECTION));
+        let mut end = match section {
+            Some(start) => target.items[start + 1..]
+                .iter()
+                .position(|item| matches!(item, LangItem::Section { .. }))
+                .map_or(target.items.len(), |offset| start + 1 + offset),
+            None => {
+                if target.items.last().is_some_and(|item| !matches!(item, LangItem::Blank(_))) {
+                    target.items.push(LangItem::Blank(String::new()));
+                }
+                target.items.push(LangItem::Section {
+                    name: FALLBACK_SECTION.to_string(),
+                    raw: format!("# === {} ===", FALLBACK_SECTION),
+                });
+                target.items.len()
+            }
+        };
+        while end > 0 && matches!(target.items[end - 1], LangItem::Blank(_)) {
+            end -= 1;
+        }
+        target.items.splice(end..end, missing.into_iter().map(LangItem::Entry));
+        target.trailing_newline = true;
+        target.write(&path)?;
+    }
+
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hytaleru_fallback_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("source")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn appends_marked_section_once() {
+        let dir = temp_dir("once");
+        let (source, target) = (dir.join("source"), dir.join("target"));
+        fs::write(source.join("client.lang"), "a = A\nb = B\n").unwrap();
+        fs::write(target.join("client.lang"), "a = А\n").unwrap();
+
+        assert_eq!(fill_missing_keys(&source, &target).unwrap(), 1);
+        let expected = "a = А\n\n# === Не переведено (en-US) ===\nb = B\n";
+        assert_eq!(fs::read_to_string(target.join("client.lang")).unwrap(), expected);
+
+        assert_eq!(fill_missing_keys(&source, &target).unwrap(), 0);
+        assert_eq!(fs::read_to_string(target.join("client.lang")).unwrap(), expected);
+
+        fs::write(source.join("client.lang"), "a = A\nb = B\nc = C\n").unwrap();
+        assert_eq!(fill_missing_keys(&source, &target).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(target.join("client.lang")).unwrap(),
+            "a = А\n\n# === Не переведено (en-US) ===\nb = B\nc = C\n"
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn creates_missing_files() {
+        let dir = temp_dir("create");
+        let (source, target) = (dir.join("source"), dir.join("target"));
+        fs::create_dir_all(source.join("sub")).unwrap();
+        fs::write(source.join("sub/items.lang"), "x = X\n").unwrap();
+
+        assert_eq!(fill_missing_keys(&source, &target).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(target.join("sub/items.lang")).unwrap(),
+            "# === Не переведено (en-US) ===\nx = X\n"
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,240 @@
+use super::lint::translation_dir;
+use super::{installed_source_tree, read_lang_tree, LangFile};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const GLOSSARY_FILE: &str = "glossary.json";
+const ENDINGS: &str = "аеёиоуыэюяйь";
+const MAX_SUFFIX: usize = 3;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Glossary {
+    #[serde(rename = "Terms")]
+    pub terms: Vec<GlossaryTerm>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct GlossaryTerm {
+    #[serde(rename = "Source")]
+    pub source: String,
+    #[serde(rename = "Target")]
+    pub target: String,
+    #[serde(rename = "Forbidden", skip_serializing_if = "Vec::is_empty")]
+    pub forbidden: Vec<String>,
+    #[serde(rename = "Note", skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GlossaryIssueKind {
+    Missing,
+    Forbidden,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GlossaryIssue {
+    pub file: String,
+    pub key: String,
+    pub line: usize,
+    pub term: String,
+    pub kind: GlossaryIssueKind,
+    pub message: String,
+}
+
+#[tauri::command]
+pub fn check_glossary(path: String, glossary: Option<String>) -> Result<Vec<GlossaryIssue>, String> {
+    let root = PathBuf::from(&path);
+    let glossary = Glossary::read(&glossary.map(PathBuf::from).unwrap_or_else(|| root.join(GLOSSARY_FILE)))?;
+    Ok(glossary.check(&installed_source_tree()?, &read_lang_tree(&translation_dir(&root))?))
+}
+
+impl Glossary {
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Ошибка чтения глоссария {}: {}", path.display(), e))?;
+        let glossary: Glossary =
+            serde_json::from_str(&content).map_err(|e| format!("Ошибка парсинга глоссария: {}", e))?;
+
+        if let Some(term) = glossary
+            .terms
+            .iter()
+            .find(|term| term.source.trim().is_empty() || term.target.trim().is_empty())
+        {
+            return Err(format!("В глоссарии есть термин без Source или Target: {:?}", term.source));
+        }
+        Ok(glossary)
+    }
+
+    pub fn check(&self, source: &BTreeMap<String, LangFile>, target: &BTreeMap<String, LangFile>) -> Vec<GlossaryIssue> {
+        let mut issues = Vec::new();
+
+        for (file, lang) in target {
+            let originals = source.get(file);
+            for entry in lang.entries() {
+                let words = words(&entry.value);
+                let original = originals.and_then(|original| original.get(&entry.key));
+
+                for term in &self.terms {
+                    let mut push = |kind, message| {
+                        issues.push(GlossaryIssue {
+                            file: file.clone(),
+                            key: entry.key.clone(),
+                            line: entry.line,
+                            term: term.source.clone(),
+                            kind,
+                            message,
+                        })
+                    };
+
+                    if let Some(variant) = term.forbidden.iter().find(|variant| contains_inflected(&words, variant)) {
+                        push(
+                            GlossaryIssueKind::Forbidden,
+                            format!("запрещённый вариант «{}», используйте «{}»", variant, term.target),
+                        );
+                    } else if original.is_some_and(|original| contains_english(&original.value, &term.source))
+                        && !contains_inflected(&words, &term.target)
+                    {
+                        push(
+                            GlossaryIssueKind::Missing,
+                            format!("«{}» должен переводиться как «{}»", term.source, term.target),
+                        );
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(normalize)
+        .collect()
+}
+
+fn normalize(word: &str) -> String {
+    word.to_lowercase().replace('ё', "е")
+}
+
+fn stem(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let mut end = chars.len();
+    while end > 3 && chars.len() - end < 2 && ENDINGS.contains(chars[end - 1]) {
+        end -= 1;
+    }
+    chars[..end].iter().collect()
+}
+
+fn contains_inflected(words: &[String], phrase: &str) -> bool {
+    let stems: Vec<String> = phrase
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| stem(&normalize(word)))
+        .collect();
+    if stems.is_empty() || stems.len() > words.len() {
+        return false;
+    }
+
+    words.windows(stems.len()).any(|window| {
+        window.iter().zip(&stems).all(|(word, stem)| {
+            word.starts_with(stem.as_str()) && word.chars().count() <= stem.chars().count() + MAX_SUFFIX
+        })
+    })
+}
+
+fn contains_english(text: &str, term: &str) -> bool {
+    let terms: Vec<String> = words(term);
+    let words = words(text);
+    if terms.is_empty() || terms.len() > words.len() {
+        return false;
+    }
+
+    words.windows(terms.len()).any(|window| {
+        window.iter().zip(&terms).enumerate().all(|(index, (word, term))| {
+            let last = index + 1 == terms.len();
+            word == term || (last && word.starts_with(term.as_str()) && matches!(&word[term.len()..], "s" | "es"))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(content: &str) -> BTreeMap<String, LangFile> {
+        BTreeMap::from([("items.lang".to_string(), LangFile::parse(content))])
+    }
+
+    fn glossary() -> Glossary {
+        Glossary {
+            terms: vec![
+                GlossaryTerm {
+                    source: "Crafting Table".to_string(),
+                    target: "верстак".to_string(),
+                    forbidden: vec!["стол крафта".to_string()],
+                    note: None,
+                },
+                GlossaryTerm {
+                    source: "box".to_string(),
+                    target: "ящик".to_string(),
+                    forbidden: Vec::new(),
+                    note: None,
+                },
+            ],
+        }
+    }
+
+    fn inflected(text: &str, phrase: &str) -> bool {
+        contains_inflected(&words(text), phrase)
+    }
+
+    #[test]
+    fn stems_russian_endings() {
+        assert_eq!(stem("верстак"), "верстак");
+        assert_eq!(stem("ящика"), "ящик");
+        assert_eq!(stem("зелье"), "зел");
+        assert_eq!(stem("мой"), "мой");
+        assert_eq!(stem("стола"), "стол");
+    }
+
+    #[test]
+    fn matches_inflected_forms() {
+        assert!(inflected("Поставьте верстаки рядом", "верстак"));
+        assert!(inflected("У верстаком", "верстак"));
+        assert!(inflected("Нет столов крафта", "стол крафта"));
+        assert!(inflected("Зелья лечения", "зелье"));
+        assert!(inflected("Ёлка", "елка"));
+        assert!(!inflected("Верстачный набор", "верстак"));
+        assert!(!inflected("Столешница крафта", "стол крафта"));
+        assert!(!inflected("Стол для крафта", "стол крафта"));
+        assert!(!inflected("Ящериц", "ящик"));
+    }
+
+    #[test]
+    fn matches_english_plurals() {
+        assert!(contains_english("Place the Crafting Table", "Crafting Table"));
+        assert!(contains_english("Two crafting tables", "Crafting Table"));
+        assert!(contains_english("Open boxes", "box"));
+        assert!(!contains_english("Open boxed set", "box"));
+        assert!(!contains_english("Crafting tablet", "Crafting Table"));
+    }
+
+    #[test]
+    fn reports_missing_and_forbidden_terms() {
+        let source = tree("a = Crafting Table\nb = Crafting Tables nearby\nc = Boxes\nd = Chest\n");
+        let target = tree("a = Стол крафта\nb = Рядом верстаки\nc = Коробки\nd = Сундук\n");
+        let issues = glossary().check(&source, &target);
+
+        let found: Vec<(&str, GlossaryIssueKind)> = issues.iter().map(|issue| (issue.key.as_str(), issue.kind)).collect();
+        assert_eq!(found, vec![("a", GlossaryIssueKind::Forbidden), ("c", GlossaryIssueKind::Missing)]);
+        assert_eq!(issues[0].message, "запрещённый вариант «стол крафта», используйте «верстак»");
+        assert_eq!(issues[1].term, "box");
+    }
+}
@@ -0,0 +1,554 @@
+use super::message::parse_message;
+use super::plural::{check_forms, numbers_without_plural, numeric_arguments};
+use super::{continues, installed_source_tree, list_lang_files, LangFile, LangItem};
+use crate::manifest::PACK_LOCALE;
+use crate::settings::load_settings;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+const HOMOGLYPHS: [(char, char); 18] = [
+    ('a', 'а'),
+    ('c', 'с'),
+    ('e', 'е'),
+    ('o', 'о'),
+    ('p', 'р'),
+    ('x', 'х'),
+    ('y', 'у'),
+    ('A', 'А'),
+    ('B', 'В'),
+    ('C', 'С'),
+    ('E', 'Е'),
+    ('H', 'Н'),
+    ('K', 'К'),
+    ('M', 'М'),
+    ('O', 'О'),
+    ('P', 'Р'),
+    ('T', 'Т'),
+    ('X', 'Х'),
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Off,
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    DuplicateKey,
+    EmptyValue,
+    Untranslated,
+    MixedScript,
+    NotNfc,
+    Bom,
+    Crlf,
+    TrailingWhitespace,
+    MalformedLine,
+    PluralForms,
+    NumberWithoutPlural,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct LintConfig {
+    pub rules: BTreeMap<LintRule, Severity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LintIssue {
+    pub file: String,
+    pub line: usize,
+    pub key: Option<String>,
+    pub rule: LintRule,
+    pub severity: Severity,
+    pub message: String,
+    pub fixed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct LintReport {
+    pub issues: Vec<LintIssue>,
+    pub errors: usize,
+    pub warnings: usize,
+    pub fixed: usize,
+}
+
+struct Linter<'a> {
+    config: &'a LintConfig,
+    source: &'a BTreeMap<String, LangFile>,
+    fix: bool,
+    seen: BTreeMap<String, (String, usize)>,
+    issues: Vec<LintIssue>,
+}
+
+#[tauri::command]
+pub fn lint_translation(path: String, fix: bool) -> Result<LintReport, String> {
+    let source = installed_source_tree()?;
+    lint_dir(&translation_dir(Path::new(&path)), &load_settings()?.lint, &source, fix)
+}
+
+pub fn translation_dir(path: &Path) -> PathBuf {
+    let nested = path.join("Language").join(PACK_LOCALE);
+    if nested.is_dir() {
+        nested
+    } else {
+        path.to_path_buf()
+    }
+}
+
+pub fn lint_dir(
+    dir: &Path,
+    config: &LintConfig,
+    source: &BTreeMap<String, LangFile>,
+    fix: bool,
+) -> Result<LintReport, String> {
+    let files = list_lang_files(dir)?;
+    if files.is_empty() {
+        return Err(format!("В папке {} нет файлов .lang", dir.display()));
+    }
+
+    let mut linter = Linter {
+        config,
+        source,
+        fix,
+        seen: BTreeMap::new(),
+        issues: Vec::new(),
+    };
+
+    for file in files {
+        let path = dir.join(&file);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Ошибка чтения {}: {}", file, e))?;
+        let mut lang = LangFile::parse(&content);
+        linter.lint_file(&file, &mut lang);
+
+        if fix && lang.serialize() != content {
+            lang.write(&path)?;
+        }
+    }
+
+    let issues = linter.issues;
+    Ok(LintReport {
+        errors: count(&issues, Severity::Error),
+        warnings: count(&issues, Severity::Warning),
+        fixed: issues.iter().filter(|issue| issue.fixed).count(),
+        issues,
+    })
+}
+
+impl LintConfig {
+    pub fn severity(&self, rule: LintRule) -> Severity {
+        self.rules.get(&rule).copied().unwrap_or(match rule {
+            LintRule::DuplicateKey | LintRule::MalformedLine | LintRule::PluralForms => Severity::Error,
+            LintRule::Untranslated | LintRule::TrailingWhitespace => Severity::Info,
+            _ => Severity::Warning,
+        })
+    }
+}
+
+impl LintRule {
+    pub fn name(self) -> &'static str {
+        match self {
+            LintRule::DuplicateKey => "duplicate-key",
+            LintRule::EmptyValue => "empty-value",
+            LintRule::Untranslated => "untranslated",
+            LintRule::MixedScript => "mixed-script",
+            LintRule::NotNfc => "not-nfc",
+            LintRule::Bom => "bom",
+            LintRule::Crlf => "crlf",
+            LintRule::TrailingWhitespace => "trailing-whitespace",
+            LintRule::MalformedLine => "malformed-line",
+            LintRule::PluralForms => "plural-forms",
+            LintRule::NumberWithoutPlural => "number-without-plural",
+        }
+    }
+}
+
+impl Linter<'_> {
+    fn report(&mut self, file: &str, line: usize, key: Option<&str>, rule: LintRule, message: String, fixable: bool) -> bool {
+        let severity = self.config.severity(rule);
+        if severity == Severity::Off {
+            return false;
+        }
+
+        let fixed = self.fix && fixable;
+        self.issues.push(LintIssue {
+            file: file.to_string(),
+            line,
+            key: key.map(str::to_string),
+            rule,
+            severity,
+            message,
+            fixed,
+        });
+        fixed
+    }
+
+    fn lint_file(&mut self, file: &str, lang: &mut LangFile) {
+        if lang.bom && self.report(file, 1, None, LintRule::Bom, "файл начинается с BOM".to_string(), true) {
+            lang.bom = false;
+        }
+
+        let crlf = lang
+            .items
+            .iter()
+            .map(|item| item.raw().split('\n').filter(|line| line.ends_with('\r')).count())
+            .sum::<usize>();
+        let strip_crlf = crlf > 0
+            && self.report(
+                file,
+                1,
+                None,
+                LintRule::Crlf,
+                format!("строк с окончанием CRLF: {}", crlf),
+                true,
+            );
+
+        let source = self.source.get(file);
+        let mut line = 1;
+        for item in &mut lang.items {
+            let raw = item.raw().to_string();
+            let start = line;
+            line += raw.matches('\n').count() + 1;
+
+            let mut lines: Vec<String> = raw.split('\n').map(str::to_string).collect();
+            if strip_crlf {
+                for text in &mut lines {
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+            }
+            for (offset, text) in lines.iter_mut().enumerate() {
+                let trimmed = text.trim_end_matches([' ', '\t', '\r']);
+                let has_trailing = trimmed.len() < text.trim_end_matches('\r').len() && !continues(trimmed);
+                if has_trailing
+                    && self.report(
+                        file,
+                        start + offset,
+                        None,
+                        LintRule::TrailingWhitespace,
+                        "пробелы в конце строки".to_string(),
+                        true,
+                    )
+                {
+                    let ending = if text.ends_with('\r') { "\r" } else { "" };
+                    *text = format!("{}{}", trimmed, ending);
+                }
+            }
+            let mut raw = lines.join("\n");
+
+            match item {
+                LangItem::Invalid(_) => {
+                    self.report(
+                        file,
+                        start,
+                        None,
+                        LintRule::MalformedLine,
+                        "строка не является записью «ключ = значение»".to_string(),
+                        false,
+                    );
+                }
+                LangItem::Entry(entry) => {
+                    raw = self.lint_entry(file, start, &entry.key, &entry.value, raw, source);
+                }
+                _ => {}
+            }
+
+            if raw != item.raw() {
+                item.set_raw(raw);
+            }
+        }
+    }
+
+    fn lint_entry(
+        &mut self,
+        file: &str,
+        line: usize,
+        key: &str,
+        value: &str,
+        mut raw: String,
+        source: Option<&LangFile>,
+    ) -> String {
+        let first = raw.split('\n').next().unwrap_or_default();
+        let (head, rest) = first.split_once('=').unwrap_or((first, ""));
+        let well_formed = head == format!("{} ", key)
+            && (rest.is_empty() || (rest.starts_with(' ') && !rest.starts_with("  ")));
+        if !well_formed
+            && self.report(
+                file,
+                line,
+                Some(key),
+                LintRule::MalformedLine,
+                "разделитель ключа и значения должен быть « = »".to_string(),
+                true,
+            )
+        {
+            let tail = &raw[first.len()..];
+            raw = format!("{} = {}", key, rest.trim_start()).trim_end().to_string() + tail;
+        }
+
+        match self.seen.get(key) {
+            Some((seen_file, seen_line)) => {
+                let message = if seen_file == file {
+                    format!("ключ уже определён в строке {}", seen_line)
+                } else {
+                    format!("ключ уже определён в {}:{}", seen_file, seen_line)
+                };
+                self.report(file, line, Some(key), LintRule::DuplicateKey, message, false);
+            }
+            None => {
+                self.seen.insert(key.to_string(), (file.to_string(), line));
+            }
+        }
+
+        if value.trim().is_empty() {
+            self.report(file, line, Some(key), LintRule::EmptyValue, "пустое значение".to_string(), false);
+        }
+
+        let original = source.and_then(|source| source.get(key));
+        if let Some(original) = original {
+            if original.value == value && value.chars().any(|c| c.is_ascii_alphabetic()) {
+                self.report(
+                    file,
+                    line,
+                    Some(key),
+                    LintRule::Untranslated,
+                    "значение совпадает с английским".to_string(),
+                    false,
+                );
+            }
+        }
+
+        let mixed: Vec<&str> = value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| is_mixed_script(word))
+            .collect();
+        if !mixed.is_empty() {
+            let fixable = mixed.iter().all(|word| fix_homoglyphs(word).is_some());
+            if self.report(
+                file,
+                line,
+                Some(key),
+                LintRule::MixedScript,
+                format!("смешение латиницы и кириллицы: {}", mixed.join(", ")),
+                fixable,
+            ) {
+                raw = replace_value(&raw, |value| {
+                    let mut fixed = value.to_string();
+                    for word in &mixed {
+                        if let Some(replacement) = fix_homoglyphs(word) {
+                            fixed = fixed.replace(word, &replacement);
+                        }
+                    }
+                    fixed
+                });
+            }
+        }
+
+        if let Ok(parts) = parse_message(value) {
+            for problem in check_forms(&parts) {
+                self.report(file, line, Some(key), LintRule::PluralForms, problem, false);
+            }
+
+            let mut numeric = numeric_arguments(&parts);
+            if let Some(original) = original.and_then(|original| parse_message(&original.value).ok()) {
+                numeric.extend(numeric_arguments(&original));
+            }
+            let counted = numbers_without_plural(&parts, &numeric);
+            if !counted.is_empty() {
+                let names: Vec<String> = counted.iter().map(|name| format!("{{{}}}", name)).collect();
+                self.report(
+                    file,
+                    line,
+                    Some(key),
+                    LintRule::NumberWithoutPlural,
+                    format!("число перед словом без plural: {}", names.join(", ")),
+                    false,
+                );
+            }
+        }
+
+        if !is_nfc(&raw)
+            && self.report(
+                file,
+                line,
+                Some(key),
+                LintRule::NotNfc,
+                "текст не нормализован в NFC".to_string(),
+                true,
+            )
+        {
+            raw = raw.nfc().collect();
+        }
+
+        raw
+    }
+}
+
+fn count(issues: &[LintIssue], severity: Severity) -> usize {
+    issues
+        .iter()
+        .filter(|issue| issue.severity == severity && !issue.fixed)
+        .count()
+}
+
+fn replace_value(raw: &str, f: impl Fn(&str) -> String) -> String {
+    match raw.split_once('=') {
+        Some((head, value)) => format!("{}={}", head, f(value)),
+        None => raw.to_string(),
+    }
+}
+
+fn is_cyrillic(c: char) -> bool {
+    matches!(c, '\u{0400}'..='\u{04FF}')
+}
+
+fn is_mixed_script(word: &str) -> bool {
+    word.chars().any(is_cyrillic) && word.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+fn fix_homoglyphs(word: &str) -> Option<String> {
+    let cyrillic = word.chars().filter(|c| is_cyrillic(*c)).count();
+    let latin = word.chars().filter(|c| c.is_ascii_alphabetic()).count();
+
+    word.chars()
+        .map(|c| {
+            if cyrillic >= latin && c.is_ascii_alphabetic() {
+                HOMOGLYPHS.iter().find(|(l, _)| *l == c).map(|(_, r)| *r)
+            } else if cyrillic < latin && is_cyrillic(c) {
+                HOMOGLYPHS.iter().find(|(_, r)| *r == c).map(|(l, _)| *l)
+            } else {
+                Some(c)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hytaleru_lint_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn rules(report: &LintReport) -> Vec<(&str, usize, LintRule)> {
+        report
+            .issues
+            .iter()
+            .map(|issue| (issue.file.as_str(), issue.line, issue.rule))
+            .collect()
+    }
+
+    #[test]
+    fn reports_duplicates_across_files() {
+        let dir = temp_dir("duplicates");
+        fs::write(dir.join("a.lang"), "x = Один\ny = Два\n").unwrap();
+        fs::write(dir.join("b.lang"), "z = Три\n\nx = Четыре\nz = Пять\n").unwrap();
+
+        let report = lint_dir(&dir, &LintConfig::default(), &BTreeMap::new(), false).unwrap();
+        assert_eq!(
+            rules(&report),
+            vec![("b.lang", 3, LintRule::DuplicateKey), ("b.lang", 4, LintRule::DuplicateKey)]
+        );
+        assert_eq!(report.issues[0].message, "ключ уже определён в a.lang:1");
+        assert_eq!(report.issues[1].message, "ключ уже определён в строке 1");
+        assert_eq!(report.errors, 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn fixes_homoglyphs_and_nfc() {
+        let dir = temp_dir("homoglyphs");
+        let path = dir.join("a.lang");
+        fs::write(&path, "a = Мeч\nb = Cтoл\nc = Hellо\nd = Еще\u{308}\n").unwrap();
+
+        let report = lint_dir(&dir, &LintConfig::default(), &BTreeMap::new(), true).unwrap();
+        assert_eq!(
+            rules(&report),
+            vec![
+                ("a.lang", 1, LintRule::MixedScript),
+                ("a.lang", 2, LintRule::MixedScript),
+                ("a.lang", 3, LintRule::MixedScript),
+                ("a.lang", 4, LintRule::NotNfc),
+            ]
+        );
+        assert_eq!((report.fixed, report.warnings), (4, 0));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a = Меч\nb = Стол\nc = Hello\nd = Ещё\n");
+
+        let report = lint_dir(&dir, &LintConfig::default(), &BTreeMap::new(), true).unwrap();
+        assert!(report.issues.is_empty());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn fixes_bom_crlf_and_trailing_whitespace() {
+        let dir = temp_dir("whitespace");
+        let path = dir.join("a.lang");
+        fs::write(&path, "\u{feff}a = Один  \r\nb = Два \\\r\n  три\r\nc = Три\r\n").unwrap();
+
+        let report = lint_dir(&dir, &LintConfig::default(), &BTreeMap::new(), true).unwrap();
+        assert_eq!(
+            rules(&report),
+            vec![
+                ("a.lang", 1, LintRule::Bom),
+                ("a.lang", 1, LintRule::Crlf),
+                ("a.lang", 1, LintRule::TrailingWhitespace),
+            ]
+        );
+        assert!(report.issues.iter().all(|issue| issue.fixed));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a = Один\nb = Два \\\n  три\nc = Три\n");
+
+        let report = lint_dir(&dir, &LintConfig::default(), &BTreeMap::new(), false).unwrap();
+        assert!(report.issues.is_empty());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn severity_overrides_disable_rules() {
+        let dir = temp_dir("severity");
+        fs::write(dir.join("a.lang"), "a = Sword\nb = Мeч\nc =\n").unwrap();
+        let source = BTreeMap::from([("a.lang".to_string(), LangFile::parse("a = Sword\n"))]);
+
+        let report = lint_dir(&dir, &LintConfig::default(), &source, false).unwrap();
+        assert_eq!(
+            rules(&report),
+            vec![
+                ("a.lang", 1, LintRule::Untranslated),
+                ("a.lang", 2, LintRule::MixedScript),
+                ("a.lang", 3, LintRule::EmptyValue),
+            ]
+        );
+        assert_eq!((report.errors, report.warnings), (0, 2));
+
+        let config = LintConfig {
+            rules: BTreeMap::from([
+                (LintRule::MixedScript, Severity::Off),
+                (LintRule::EmptyValue, Severity::Error),
+            ]),
+        };
+        let report = lint_dir(&dir, &config, &source, true).unwrap();
+        assert_eq!(rules(&report), vec![("a.lang", 1, LintRule::Untranslated), ("a.lang", 3, LintRule::EmptyValue)]);
+        assert_eq!((report.errors, report.warnings, report.fixed), (1, 0, 0));
+        assert_eq!(fs::read_to_string(dir.join("a.lang")).unwrap(), "a = Sword\nb = Мeч\nc =\n");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rejects_empty_dir() {
+        let dir = temp_dir("empty");
+        assert!(lint_dir(&dir, &LintConfig::default(), &BTreeMap::new(), false).is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
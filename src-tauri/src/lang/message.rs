@@ -0,0 +1,370 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessagePart {
+    Text(String),
+    Pound,
+    Argument {
+        name: String,
+        format: Option<String>,
+        style: Option<String>,
+    },
+    Choice {
+        name: String,
+        kind: String,
+        arms: Vec<(String, Vec<MessagePart>)>,
+    },
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    spans: Vec<Range<usize>>,
+}
+
+pub fn parse_message(text: &str) -> Result<Vec<MessagePart>, String> {
+    Parser::run(text).map(|(parts, _)| parts)
+}
+
+pub fn text_ranges(text: &str) -> Result<Vec<Range<usize>>, String> {
+    let (_, spans) = Parser::run(text)?;
+    let mut offsets: Vec<usize> = text.char_indices().map(|(offset, _)| offset).collect();
+    offsets.push(text.len());
+
+    let mut ranges = Vec::new();
+    for span in spans {
+        let start = offsets[span.start];
+        let end = offsets[span.end];
+        let mut cursor = start;
+        for tag in tag_ranges(&text[start..end]) {
+            if cursor < start + tag.start {
+                ranges.push(cursor..start + tag.start);
+            }
+            cursor = start + tag.end;
+        }
+        if cursor < end {
+            ranges.push(cursor..end);
+        }
+    }
+    Ok(ranges)
+}
+
+pub fn argument_names(parts: &[MessagePart]) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    visit(parts, &mut |part| match part {
+        MessagePart::Argument { name, .. } | MessagePart::Choice { name, .. } => {
+            names.insert(name.clone());
+        }
+        _ => {}
+    });
+    names
+}
+
+pub fn markup_tags(parts: &[MessagePart]) -> BTreeMap<String, usize> {
+    let mut tags = BTreeMap::new();
+    visit(parts, &mut |part| {
+        if let MessagePart::Text(text) = part {
+            for tag in scan_tags(text) {
+                *tags.entry(tag).or_insert(0) += 1;
+            }
+        }
+    });
+    tags
+}
+
+pub fn visit(parts: &[MessagePart], f: &mut impl FnMut(&MessagePart)) {
+    for part in parts {
+        f(part);
+        if let MessagePart::Choice { arms, .. } = part {
+            for (_, arm) in arms {
+                visit(arm, f);
+            }
+        }
+    }
+}
+
+fn scan_tags(text: &str) -> Vec<String> {
+    tag_ranges(text)
+        .into_iter()
+        .map(|range| text[range].split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect()
+}
+
+fn tag_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut tags = Vec::new();
+    let mut offset = 0;
+
+    while let Some(found) = text[offset..].find('<') {
+        let start = offset + found;
+        let candidate = &text[start..];
+        let name_start = if candidate.starts_with("</") { 2 } else { 1 };
+        let is_tag = candidate[name_start..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic());
+
+        match candidate.find('>').filter(|_| is_tag) {
+            Some(end) => {
+                tags.push(start..start + end + 1);
+                offset = start + end + 1;
+            }
+            None => offset = start + 1,
+        }
+    }
+
+    tags
+}
+
+impl Parser {
+    fn run(text: &str) -> Result<(Vec<MessagePart>, Vec<Range<usize>>), String> {
+        let mut parser = Parser {
+            chars: text.chars().collect(),
+            pos: 0,
+            spans: Vec::new(),
+        };
+        let parts = parser.parse_parts(false, false)?;
+        if parser.pos < parser.chars.len() {
+            return Err(format!("Лишняя закрывающая скобка в позиции {}", parser.pos + 1));
+        }
+        Ok((parts, parser.spans))
+    }
+
+    fn push_text(&mut self, parts: &mut Vec<MessagePart>, text: &mut String) {
+        if !text.is_empty() {
+            let length = text.chars().count();
+            self.spans.push(self.pos - length..self.pos);
+            parts.push(MessagePart::Text(std::mem::take(text)));
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_parts(&mut self, in_arm: bool, pound: bool) -> Result<Vec<MessagePart>, String> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    self.push_text(&mut parts, &mut text);
+                    self.pos += 1;
+                    parts.push(self.parse_argument(pound)?);
+                }
+                '}' if in_arm => break,
+                '}' => return Err(format!("Лишняя закрывающая скобка в позиции {}", self.pos + 1)),
+                '#' if pound => {
+                    self.push_text(&mut parts, &mut text);
+                    self.pos += 1;
+                    parts.push(MessagePart::Pound);
+                }
+                _ => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        self.push_text(&mut parts, &mut text);
+        Ok(parts)
+    }
+
+    fn parse_argument(&mut self, pound: bool) -> Result<MessagePart, String> {
+        let name = self.read_until(&[',', '}'])?.trim().to_string();
+        if name.is_empty() {
+            return Err(format!("Пустое имя аргумента в позиции {}", self.pos));
+        }
+
+        if self.next_char()? == '}' {
+            return Ok(MessagePart::Argument {
+                name,
+                format: None,
+                style: None,
+            });
+        }
+
+        let format = self.read_until(&[',', '}'])?.trim().to_string();
+        let closing = self.next_char()?;
+
+        if !matches!(format.as_str(), "plural" | "select" | "selectordinal") {
+            let style = if closing == ',' {
+                Some(self.read_style()?.trim().to_string())
+            } else {
+                None
+            };
+            return Ok(MessagePart::Argument {
+                name,
+                format: Some(format),
+                style,
+            });
+        }
+
+        if closing == '}' {
+            return Err(format!("У аргумента {} нет вариантов {}", name, format));
+        }
+
+        let mut arms = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                None => return Err(format!("Незакрытая скобка в аргументе {}", name)),
+                _ => {}
+            }
+
+            let selector = self.read_selector();
+            if selector.starts_with("offset:") {
+                continue;
+            }
+            if selector.is_empty() {
+                return Err(format!("Пустой вариант в аргументе {}", name));
+            }
+
+            self.skip_whitespace();
+            if self.next_char()? != '{' {
+                return Err(format!("Ожидалась {{ после варианта {} в аргументе {}", selector, name));
+            }
+            let arm = self.parse_parts(true, pound || format != "select")?;
+            if self.next_char()? != '}' {
+                return Err(format!("Незакрытый вариант {} в аргументе {}", selector, name));
+            }
+            arms.push((selector, arm));
+        }
+
+        Ok(MessagePart::Choice { name, kind: format, arms })
+    }
+
+    fn next_char(&mut self) -> Result<char, String> {
+        let c = self.peek().ok_or("Незакрытая фигурная скобка")?;
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn read_until(&mut self, stops: &[char]) -> Result<String, String> {
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            if stops.contains(&c) {
+                return Ok(value);
+            }
+            if c == '{' {
+                return Err(format!("Неожиданная открывающая скобка в позиции {}", self.pos + 1));
+            }
+            value.push(c);
+            self.pos += 1;
+        }
+        Err("Незакрытая фигурная скобка".to_string())
+    }
+
+    fn read_style(&mut self) -> Result<String, String> {
+        let mut value = String::new();
+        let mut depth = 0;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return Ok(value),
+                '}' => depth -= 1,
+                _ => {}
+            }
+            value.push(c);
+        }
+        Err("Незакрытая фигурная скобка".to_string())
+    }
+
+    fn read_selector(&mut self) -> String {
+        let mut selector = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || c == '{' || c == '}' {
+                break;
+            }
+            selector.push(c);
+            self.pos += 1;
+        }
+        selector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> MessagePart {
+        MessagePart::Text(value.to_string())
+    }
+
+    #[test]
+    fn parses_arguments_and_text() {
+        let parts = parse_message("Привет, {name}! У вас {count, number, integer} монет").unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                text("Привет, "),
+                MessagePart::Argument {
+                    name: "name".to_string(),
+                    format: None,
+                    style: None,
+                },
+                text("! У вас "),
+                MessagePart::Argument {
+                    name: "count".to_string(),
+                    format: Some("number".to_string()),
+                    style: Some("integer".to_string()),
+                },
+                text(" монет"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_nested_choices() {
+        let parts = parse_message("{count, plural, offset:1 one {# предмет} other {{kind, select, a {A} other {#}}}}").unwrap();
+        let MessagePart::Choice { name, kind, arms } = &parts[0] else {
+            panic!("{:?}", parts);
+        };
+        assert_eq!((name.as_str(), kind.as_str()), ("count", "plural"));
+        assert_eq!(arms[0], ("one".to_string(), vec![MessagePart::Pound, text(" предмет")]));
+        assert_eq!(arms[1].0, "other");
+        assert_eq!(
+            argument_names(&parts).into_iter().collect::<Vec<_>>(),
+            vec!["count".to_string(), "kind".to_string()]
+        );
+    }
+
+    #[test]
+    fn pound_is_text_outside_plural() {
+        assert_eq!(parse_message("#1 {x, select, a {#}}").unwrap()[0], text("#1 "));
+    }
+
+    #[test]
+    fn rejects_unbalanced_braces() {
+        for message in ["{name", "name}", "{}", "{count, plural}", "{count, plural, one {x}", "{a{b}}"] {
+            assert!(parse_message(message).is_err(), "{}", message);
+        }
+    }
+
+    #[test]
+    fn counts_markup_tags() {
+        let tags = markup_tags(&parse_message("<b>Привет</b>, <br/>{name}<br/>").unwrap());
+        assert_eq!(tags.get("<b>"), Some(&1));
+        assert_eq!(tags.get("</b>"), Some(&1));
+        assert_eq!(tags.get("<br/>"), Some(&2));
+    }
+
+    #[test]
+    fn text_ranges_skip_arguments_and_tags() {
+        let message = "<b>Привет</b>, {name}";
+        let ranges: Vec<&str> = text_ranges(message).unwrap().into_iter().map(|range| &message[range]).collect();
+        assert_eq!(ranges, vec!["Привет", ", "]);
+    }
+}
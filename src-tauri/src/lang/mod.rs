@@ -0,0 +1,455 @@
+pub mod coverage;
+pub mod exchange;
+pub mod fallback;
+pub mod glossary;
+pub mod lint;
+pub mod message;
+pub mod plural;
+pub mod stale;
+pub mod typography;
+pub mod validate;
+
+use crate::dependencies::{resolve_plan, DependencyPlan};
+use crate::gamepath::hytale_game_dir;
+use crate::manifest::PACK_LOCALE;
+use crate::providers::provider_for_game;
+use hytaleru_lib::write_atomic;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const SOURCE_LOCALE: &str = "en-US";
+const BOM: char = '\u{feff}';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LangItem {
+    Blank(String),
+    Comment(String),
+    Section { name: String, raw: String },
+    Entry(LangEntry),
+    Invalid(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LangEntry {
+    pub key: String,
+    pub value: String,
+    pub raw: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LangFile {
+    pub items: Vec<LangItem>,
+    pub bom: bool,
+    pub trailing_newline: bool,
+}
+
+impl LangItem {
+    pub fn raw(&self) -> &str {
+        match self {
+            LangItem::Blank(raw) | LangItem::Comment(raw) | LangItem::Invalid(raw) => raw,
+            LangItem::Section { raw, .. } => raw,
+            LangItem::Entry(entry) => &entry.raw,
+        }
+    }
+
+    pub fn set_raw(&mut self, raw: String) {
+        match self {
+            LangItem::Blank(current) | LangItem::Comment(current) | LangItem::Invalid(current) => *current = raw,
+            LangItem::Section { raw: current, .. } => *current = raw,
+            LangItem::Entry(entry) => {
+                if let Some(parsed) = LangFile::parse(&raw).entries().next() {
+                    entry.key = parsed.key.clone();
+                    entry.value = parsed.value.clone();
+                }
+                entry.raw = raw;
+            }
+        }
+    }
+}
+
+impl LangEntry {
+    pub fn new(key: &str, value: &str) -> Self {
+        LangEntry {
+            key: key.to_string(),
+            value: value.to_string(),
+            raw: format!("{} = {}", key, escape(value)),
+            line: 0,
+        }
+    }
+
+    pub fn set_value(&mut self, value: &str) {
+        let escaped = if self.raw.contains('\n') {
+            let lines: Vec<String> = value.split_inclusive('\n').map(escape).collect();
+            lines.join("\\\n")
+        } else {
+            escape(value)
+        };
+        self.raw = format!("{} = {}", self.key, escaped);
+        self.value = value.to_string();
+    }
+
+    pub fn raw_value(&self) -> &str {
+        self.raw
+            .split_once('=')
+            .map(|(_, value)| value.trim_start())
+            .unwrap_or_default()
+    }
+}
+
+impl LangFile {
+    pub fn parse(content: &str) -> Self {
+        let (bom, content) = match content.strip_prefix(BOM) {
+            Some(rest) => (true, rest),
+            None => (false, content),
+        };
+        let (trailing_newline, content) = match content.strip_suffix('\n') {
+            Some(rest) => (true, rest),
+            None => (false, content),
+        };
+
+        let mut file = LangFile {
+            items: Vec::new(),
+            bom,
+            trailing_newline,
+        };
+        if content.is_empty() && !trailing_newline {
+            return file;
+        }
+
+        let lines: Vec<&str> = content.split('\n').collect();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            let text = line.strip_suffix('\r').unwrap_or(line);
+            let trimmed = text.trim();
+
+            if trimmed.is_empty() {
+                file.items.push(LangItem::Blank(line.to_string()));
+            } else if trimmed.starts_with('#') {
+                file.items.push(match section_name(trimmed) {
+                    Some(name) => LangItem::Section {
+                        name,
+                        raw: line.to_string(),
+                    },
+                    None => LangItem::Comment(line.to_string()),
+                });
+            } else if let Some((key, _)) = text.split_once('=') {
+                let start = i;
+                while i + 1 < lines.len() && continues(lines[i]) {
+                    i += 1;
+                }
+
+                let raw = lines[start..=i].join("\n");
+                file.items.push(LangItem::Entry(LangEntry {
+                    key: key.trim().to_string(),
+                    value: unescape(&logical_value(&lines[start..=i])),
+                    raw,
+                    line: start + 1,
+                }));
+            } else {
+                file.items.push(LangItem::Invalid(line.to_string()));
+            }
+            i += 1;
+        }
+
+        file
+    }
+
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Ошибка чтения {}: {}", path.display(), e))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Ошибка создания директории: {}", e))?;
+        }
+        write_atomic(path, self.serialize().as_bytes())
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut content = String::new();
+        if self.bom {
+            content.push(BOM);
+        }
+        content.push_str(
+            &self
+                .items
+                .iter()
+                .map(LangItem::raw)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        if self.trailing_newline {
+            content.push('\n');
+        }
+        content
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &LangEntry> {
+        self.items.iter().filter_map(|item| match item {
+            LangItem::Entry(entry) => Some(entry),
+            _ => None,
+        })
+    }
+
+    pub fn entries_with_sections(&self) -> Vec<(Option<&str>, &LangEntry)> {
+        let mut section = None;
+        let mut entries = Vec::new();
+        for item in &self.items {
+            match item {
+                LangItem::Section { name, .. } => section = Some(name.as_str()),
+                LangItem::Entry(entry) => entries.push((section, entry)),
+                _ => {}
+            }
+        }
+        entries
+    }
+
+    pub fn get(&self, key: &str) -> Option<&LangEntry> {
+        self.entries().filter(|entry| entry.key == key).last()
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.upsert(LangEntry::new(key, value));
+    }
+
+    pub fn merge(&mut self, overrides: &LangFile) {
+        for entry in overrides.entries() {
+            self.upsert(entry.clone());
+        }
+    }
+
+    pub fn upsert(&mut self, entry: LangEntry) {
+        let mut replaced = false;
+        for item in &mut self.items {
+            if let LangItem::Entry(existing) = item {
+                if existing.key == entry.key {
+                    *existing = LangEntry {
+                        line: existing.line,
+                        ..entry.clone()
+                    };
+                    replaced = true;
+                }
+            }
+        }
+
+        if !replaced {
+            if self.items.is_empty() {
+                self.trailing_newline = true;
+            }
+            self.items.push(LangItem::Entry(entry));
+        }
+    }
+}
+
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('u') => {
+                let code: String = chars.clone().take(4).collect();
+                match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) if code.len() == 4 => {
+                        result.push(decoded);
+                        chars.nth(3);
+                    }
+                    _ => result.push_str("\\u"),
+                }
+            }
+            Some(other @ ('\\' | ' ' | '=' | ':' | '#')) => result.push(other),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+pub fn game_lang_dir(game: &Path, locale: &str) -> PathBuf {
+    game.join("Client/Data/Shared/Language").join(locale)
+}
+
+pub fn read_lang_tree(root: &Path) -> Result<BTreeMap<String, LangFile>, String> {
+    let mut tree = BTreeMap::new();
+    for file in list_lang_files(root)? {
+        let lang = LangFile::read(&root.join(&file))?;
+        tree.insert(file, lang);
+    }
+    Ok(tree)
+}
+
+pub fn game_source_tree(game: &Path) -> Result<BTreeMap<String, LangFile>, String> {
+    let source = read_lang_tree(&game_lang_dir(game, SOURCE_LOCALE))?;
+    if source.is_empty() {
+        return Err("Файлы локализации en-US в папке игры не найдены".to_string());
+    }
+    Ok(source)
+}
+
+pub fn installed_source_tree() -> Result<BTreeMap<String, LangFile>, String> {
+    match hytale_game_dir() {
+        Ok(game) => read_lang_tree(&game_lang_dir(&game, SOURCE_LOCALE)),
+        Err(_) => Ok(BTreeMap::new()),
+    }
+}
+
+pub fn pack_lang_tree(game: &Path) -> Result<BTreeMap<String, LangFile>, String> {
+    plan_lang_tree(&resolve_plan(&provider_for_game(game)?.id)?)
+}
+
+pub fn plan_lang_tree(plan: &DependencyPlan) -> Result<BTreeMap<String, LangFile>, String> {
+    let mut tree = BTreeMap::new();
+    for pack in &plan.order {
+        merge_lang_tree(&mut tree, read_lang_tree(&pack.dir.join("Language").join(PACK_LOCALE))?);
+    }
+    Ok(tree)
+}
+
+pub fn merge_lang_tree(tree: &mut BTreeMap<String, LangFile>, overrides: BTreeMap<String, LangFile>) {
+    for (file, lang) in overrides {
+        match tree.get_mut(&file) {
+            Some(base) => base.merge(&lang),
+            None => {
+                tree.insert(file, lang);
+            }
+        }
+    }
+}
+
+pub fn list_lang_files(root: &Path) -> Result<Vec<String>, String> {
+    let mut files = Vec::new();
+    collect_lang_files(root, root, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_lang_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<(), String> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir).map_err(|e| format!("Ошибка чтения директории: {}", e))? {
+        let entry = entry.map_err(|e| format!("Ошибка чтения записи: {}", e))?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_lang_files(root, &path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "lang") {
+            if let Ok(relative) = path.strip_prefix(root) {
+                files.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn section_name(comment: &str) -> Option<String> {
+    let inner = comment
+        .trim_start_matches('#')
+        .trim()
+        .strip_prefix("===")?
+        .strip_suffix("===")?
+        .trim();
+    (!inner.is_empty()).then(|| inner.to_string())
+}
+
+fn continues(line: &str) -> bool {
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    line.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+}
+
+fn logical_value(lines: &[&str]) -> String {
+    let mut value = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let text = if index == 0 {
+            line.split_once('=').map(|(_, value)| value.trim_start()).unwrap_or_default()
+        } else {
+            line.trim_start()
+        };
+
+        match text.strip_suffix('\\').filter(|_| continues(line)) {
+            Some(joined) => value.push_str(joined),
+            None => value.push_str(text),
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_preserves_source() {
+        let samples = [
+            "",
+            "\n",
+            "a = b",
+            "\u{feff}a = b\r\nc=d\r\n",
+            "# comment\n\n# === Main ===\na = 1\n",
+            "a = first \\\n    second\\\\\nb = 3\\",
+            "a = 1\na = 2\n",
+            "  # === Indented ===\n junk line\nkey=value",
+        ];
+        for sample in samples {
+            assert_eq!(LangFile::parse(sample).serialize(), sample, "{:?}", sample);
+        }
+    }
+
+    #[test]
+    fn parse_reads_structure() {
+        let file = LangFile::parse("\u{feff}# === Main ===\r\na = x\\n\\\r\n  y\\ z\\\\\r\na = last\r\nb=\\u0041");
+        assert!(file.bom);
+        assert!(!file.trailing_newline);
+        assert_eq!(file.get("a").unwrap().value, "last");
+        assert_eq!(file.entries().next().unwrap().value, "x\ny z\\");
+        assert_eq!(file.get("b").unwrap().value, "A");
+        assert_eq!(file.entries_with_sections()[0].0, Some("Main"));
+    }
+
+    #[test]
+    fn set_escapes_value() {
+        let mut file = LangFile::default();
+        file.set("k", "a\nb\\");
+        assert_eq!(file.serialize(), "k = a\\nb\\\\\n");
+        assert_eq!(LangFile::parse(&file.serialize()).get("k").unwrap().value, "a\nb\\");
+    }
+
+    #[test]
+    fn merge_overrides_keys_in_place() {
+        let mut base = LangFile::parse("# head\na = 1\nb = 2\n");
+        base.merge(&LangFile::parse("b = 3\nc = 4\n"));
+        assert_eq!(base.serialize(), "# head\na = 1\nb = 3\nc = 4\n");
+    }
+}
@@ -0,0 +1,171 @@
+use super::message::{visit, MessagePart};
+use std::collections::BTreeSet;
+
+pub const PLURAL_CATEGORIES: [&str; 4] = ["one", "few", "many", "other"];
+pub const ORDINAL_CATEGORIES: [&str; 1] = ["other"];
+
+const UNCOUNTED_WORDS: [&str; 7] = ["или", "для", "при", "без", "над", "под", "через"];
+
+pub fn check_forms(parts: &[MessagePart]) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    visit(parts, &mut |part| {
+        let MessagePart::Choice { name, kind, arms } = part else {
+            return;
+        };
+        let selectors: BTreeSet<&str> = arms.iter().map(|(selector, _)| selector.as_str()).collect();
+        let required: &[&str] = match kind.as_str() {
+            "plural" => &PLURAL_CATEGORIES,
+            "selectordinal" => &ORDINAL_CATEGORIES,
+            _ => &["other"],
+        };
+
+        let missing: Vec<&str> = required
+            .iter()
+            .copied()
+            .filter(|category| !selectors.contains(category))
+            .collect();
+        if !missing.is_empty() {
+            problems.push(format!("в {{{}, {}}} нет форм: {}", name, kind, missing.join(", ")));
+        }
+
+        if kind != "select" {
+            let unknown: Vec<&str> = selectors
+                .iter()
+                .copied()
+                .filter(|selector| !selector.starts_with('=') && !required.contains(selector))
+                .collect();
+            if !unknown.is_empty() {
+                problems.push(format!(
+                    "в {{{}, {}}} формы не используются в русском: {}",
+                    name,
+                    kind,
+                    unknown.join(", ")
+                ));
+            }
+        }
+    });
+
+    problems
+}
+
+pub fn numeric_arguments(parts: &[MessagePart]) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    visit(parts, &mut |part| match part {
+        MessagePart::Argument {
+            name,
+            format: Some(format),
+            ..
+        } if format == "number" => {
+            names.insert(name.clone());
+        }
+        MessagePart::Choice { name, kind, .. } if kind != "select" => {
+            names.insert(name.clone());
+        }
+        _ => {}
+    });
+    names
+}
+
+pub fn numbers_without_plural(parts: &[MessagePart], numeric: &BTreeSet<String>) -> Vec<String> {
+    let mut pluralized = BTreeSet::new();
+    visit(parts, &mut |part| {
+        if let MessagePart::Choice { name, kind, .. } = part {
+            if kind == "plural" {
+                pluralized.insert(name.clone());
+            }
+        }
+    });
+
+    let mut flagged = BTreeSet::new();
+    collect_counted(parts, numeric, &pluralized, &mut flagged);
+    flagged.into_iter().collect()
+}
+
+fn collect_counted(
+    parts: &[MessagePart],
+    numeric: &BTreeSet<String>,
+    pluralized: &BTreeSet<String>,
+    flagged: &mut BTreeSet<String>,
+) {
+    for (index, part) in parts.iter().enumerate() {
+        match part {
+            MessagePart::Argument { name, .. } if numeric.contains(name) && !pluralized.contains(name) => {
+                if let Some(MessagePart::Text(text)) = parts.get(index + 1) {
+                    if starts_with_counted_word(text) {
+                        flagged.insert(name.clone());
+                    }
+                }
+            }
+            MessagePart::Choice { arms, .. } => {
+                for (_, arm) in arms {
+                    collect_counted(arm, numeric, pluralized, flagged);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn starts_with_counted_word(text: &str) -> bool {
+    let Some(rest) = text.strip_prefix([' ', '\u{a0}']) else {
+        return false;
+    };
+    let word: String = rest.chars().take_while(|c| c.is_alphabetic()).collect();
+    let abbreviation = rest[word.len()..].starts_with('.');
+
+    word.chars().count() >= 3 && !abbreviation && !UNCOUNTED_WORDS.contains(&word.to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::message::parse_message;
+
+    fn forms(text: &str) -> Vec<String> {
+        check_forms(&parse_message(text).unwrap())
+    }
+
+    fn counted(text: &str) -> Vec<String> {
+        let parts = parse_message(text).unwrap();
+        numbers_without_plural(&parts, &numeric_arguments(&parts))
+    }
+
+    #[test]
+    fn requires_russian_plural_forms() {
+        assert!(forms("{n, plural, one {# предмет} few {# предмета} many {# предметов} other {# предмета}}").is_empty());
+        assert!(forms("{n, plural, =0 {нет} one {#} few {#} many {#} other {#}}").is_empty());
+        assert_eq!(
+            forms("{n, plural, one {# предмет} other {# предметов}}"),
+            vec!["в {n, plural} нет форм: few, many"]
+        );
+        assert_eq!(
+            forms("{n, plural, zero {0} one {1} few {2} many {5} other {#}}"),
+            vec!["в {n, plural} формы не используются в русском: zero"]
+        );
+        assert_eq!(
+            forms("{n, selectordinal, one {#-й} other {#-й}}"),
+            vec!["в {n, selectordinal} формы не используются в русском: one"]
+        );
+        assert_eq!(forms("{g, select, male {он} female {она}}"), vec!["в {g, select} нет форм: other"]);
+    }
+
+    #[test]
+    fn checks_nested_choices() {
+        let text = "{g, select, male {{n, plural, one {#} other {#}}} other {{n, plural, one {#} few {#} many {#} other {#}}}}";
+        assert_eq!(forms(text), vec!["в {n, plural} нет форм: few, many"]);
+    }
+
+    #[test]
+    fn flags_numbers_followed_by_words() {
+        assert_eq!(counted("Осталось {n, number} предметов"), vec!["n"]);
+        assert_eq!(counted("Получено {n} предметов"), Vec::<String>::new());
+        assert_eq!(counted("{n, number}\u{a0}монет"), vec!["n"]);
+        assert!(counted("{n, number} или больше").is_empty());
+        assert!(counted("{n, number} для игрока").is_empty());
+        assert!(counted("{n, number} сек. осталось").is_empty());
+        assert!(counted("{n, number} шт").is_empty());
+        assert!(counted("{n, number}: предметы").is_empty());
+        assert!(counted("{n, number} {n, plural, one {предмет} few {предмета} many {предметов} other {предмета}}").is_empty());
+    }
+}
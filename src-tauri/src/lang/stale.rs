@@ -0,0 +1,346 @@
+use super::lint::translation_dir;
+use super::{game_source_tree, read_lang_tree, LangFile};
+use crate::game_build::detect_game_build;
+use crate::gamepath::hytale_game_dir;
+use hytaleru_lib::{sha256_hex, write_atomic};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const SOURCE_HASHES_FILE: &str = "source-hashes.json";
+
+type KeyHashes = BTreeMap<String, BTreeMap<String, String>>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SourceHashes {
+    #[serde(rename = "Translated")]
+    pub translated: KeyHashes,
+    #[serde(rename = "Builds")]
+    pub builds: Vec<BuildSnapshot>,
+    #[serde(rename = "Texts")]
+    pub texts: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct BuildSnapshot {
+    #[serde(rename = "Build")]
+    pub build: String,
+    #[serde(rename = "Hashes")]
+    pub hashes: KeyHashes,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StaleEntry {
+    pub file: String,
+    pub key: String,
+    pub line: usize,
+    pub old: Option<String>,
+    pub new: String,
+    pub diff: String,
+    pub since_build: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct StaleReport {
+    pub game_build: Option<String>,
+    pub tracked: usize,
+    pub untracked: usize,
+    pub stale: Vec<StaleEntry>,
+}
+
+#[tauri::command]
+pub fn check_stale_translations(path: String) -> Result<StaleReport, String> {
+    let root = PathBuf::from(&path);
+    let game = hytale_game_dir()?;
+    let hashes = SourceHashes::read(&root.join(SOURCE_HASHES_FILE))?;
+    let mut report = hashes.check(&game_source_tree(&game)?, &read_lang_tree(&translation_dir(&root))?);
+    report.game_build = detect_game_build(&game);
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn record_source_hashes(path: String, accept: Vec<String>) -> Result<StaleReport, String> {
+    let root = PathBuf::from(&path);
+    record_dir(&root, &hytale_game_dir()?, &accept.into_iter().collect())
+}
+
+pub fn record_dir(root: &Path, game: &Path, accept: &BTreeSet<String>) -> Result<StaleReport, String> {
+    let path = root.join(SOURCE_HASHES_FILE);
+    let source = game_source_tree(game)?;
+    let target = read_lang_tree(&translation_dir(root))?;
+    let build = detect_game_build(game);
+
+    let mut hashes = SourceHashes::read(&path)?;
+    hashes.record(&source, &target, build.as_deref(), accept);
+    hashes.write(&path)?;
+
+    let mut report = hashes.check(&source, &target);
+    report.game_build = build;
+    Ok(report)
+}
+
+pub fn accept_key(file: &str, key: &str) -> String {
+    format!("{}:{}", file, key)
+}
+
+impl SourceHashes {
+    pub fn read(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(SourceHashes::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Ошибка чтения {}: {}", path.display(), e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Ошибка парсинга {}: {}", path.display(), e))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Ошибка сериализации {}: {}", SOURCE_HASHES_FILE, e))?;
+        write_atomic(path, content.as_bytes())
+    }
+
+    pub fn record(
+        &mut self,
+        source: &BTreeMap<String, LangFile>,
+        target: &BTreeMap<String, LangFile>,
+        build: Option<&str>,
+        accept: &BTreeSet<String>,
+    ) {
+        let mut translated = KeyHashes::new();
+        let mut snapshot = KeyHashes::new();
+
+        for (file, lang) in target {
+            let Some(original) = source.get(file) else {
+                continue;
+            };
+            for entry in lang.entries() {
+                let Some(current) = original.get(&entry.key) else {
+                    continue;
+                };
+                let hash = sha256_hex(current.value.as_bytes());
+                self.texts.insert(hash.clone(), current.value.clone());
+
+                let recorded = match self.hash(file, &entry.key) {
+                    Some(previous) if !accept.contains(&accept_key(file, &entry.key)) => previous.clone(),
+                    _ => hash.clone(),
+                };
+                translated.entry(file.clone()).or_default().insert(entry.key.clone(), recorded);
+                snapshot.entry(file.clone()).or_default().insert(entry.key.clone(), hash);
+            }
+        }
+        self.translated = translated;
+
+        if let Some(build) = build {
+            let snapshot = BuildSnapshot {
+                build: build.to_string(),
+                hashes: snapshot,
+            };
+            match self.builds.iter_mut().find(|existing| existing.build == build) {
+                Some(existing) => *existing = snapshot,
+                None => self.builds.push(snapshot),
+            }
+        }
+
+        let used: BTreeSet<&String> = self
+            .translated
+            .values()
+            .chain(self.builds.iter().flat_map(|snapshot| snapshot.hashes.values()))
+            .flat_map(|keys| keys.values())
+            .collect();
+        self.texts.retain(|hash, _| used.contains(hash));
+    }
+
+    pub fn check(&self, source: &BTreeMap<String, LangFile>, target: &BTreeMap<String, LangFile>) -> StaleReport {
+        let mut report = StaleReport::default();
+
+        for (file, lang) in target {
+            let Some(original) = source.get(file) else {
+                continue;
+            };
+            let mut seen = BTreeSet::new();
+            for entry in lang.entries() {
+                let Some(current) = original.get(&entry.key) else {
+                    continue;
+                };
+                if !seen.insert(entry.key.as_str()) {
+                    continue;
+                }
+                let Some(recorded) = self.hash(file, &entry.key) else {
+                    report.untracked += 1;
+                    continue;
+                };
+
+                report.tracked += 1;
+                let hash = sha256_hex(current.value.as_bytes());
+                if *recorded == hash {
+                    continue;
+                }
+
+                let old = self.texts.get(recorded).cloned();
+                report.stale.push(StaleEntry {
+                    file: file.clone(),
+                    key: entry.key.clone(),
+                    line: entry.line,
+                    diff: old
+                        .as_deref()
+                        .map(|old| word_diff(old, &current.value))
+                        .unwrap_or_else(|| current.value.clone()),
+                    old,
+                    new: current.value.clone(),
+                    since_build: self
+                        .builds
+                        .iter()
+                        .find(|snapshot| {
+                            snapshot.hashes.get(file).and_then(|keys| keys.get(&entry.key)) == Some(&hash)
+                        })
+                        .map(|snapshot| snapshot.build.clone()),
+                });
+            }
+        }
+
+        report
+    }
+
+    fn hash(&self, file: &str, key: &str) -> Option<&String> {
+        self.translated.get(file).and_then(|keys| keys.get(key))
+    }
+}
+
+pub fn word_diff(old: &str, new: &str) -> String {
+    let old = tokens(old);
+    let new = tokens(new);
+
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut removed, mut added) = (String::new(), String::new());
+    let flush = |diff: &mut String, removed: &mut String, added: &mut String| {
+        if !removed.is_empty() {
+            diff.push_str(&format!("[-{}-]", std::mem::take(removed)));
+        }
+        if !added.is_empty() {
+            diff.push_str(&format!("{{+{}+}}", std::mem::take(added)));
+        }
+    };
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            flush(&mut diff, &mut removed, &mut added);
+            diff.push_str(old[i]);
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || common[i][j + 1] >= common[i + 1][j]) {
+            added.push_str(new[j]);
+            j += 1;
+        } else {
+            removed.push_str(old[i]);
+            i += 1;
+        }
+    }
+    flush(&mut diff, &mut removed, &mut added);
+    diff
+}
+
+fn tokens(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut previous: Option<bool> = None;
+
+    for (offset, c) in text.char_indices() {
+        let word = c.is_alphanumeric();
+        if previous.is_some_and(|previous| previous != word || !word) {
+            tokens.push(&text[start..offset]);
+            start = offset;
+        }
+        previous = Some(word);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(content: &str) -> BTreeMap<String, LangFile> {
+        BTreeMap::from([("items.lang".to_string(), LangFile::parse(content))])
+    }
+
+    #[test]
+    fn diffs_words() {
+        assert_eq!(word_diff("Deals 5 damage", "Deals 10 damage"), "Deals [-5-]{+10+} damage");
+        assert_eq!(word_diff("Open the box", "Open box"), "Open [-the -]box");
+        assert_eq!(word_diff("Open", "Open the box"), "Open{+ the box+}");
+        assert_eq!(word_diff("same", "same"), "same");
+    }
+
+    #[test]
+    fn detects_changed_sources_since_build() {
+        let target = tree("a = Меч\nb = Щит\nc = Лук\n");
+        let mut hashes = SourceHashes::default();
+        hashes.record(&tree("a = Sword\nb = Shield\n"), &target, Some("2026.1.1"), &BTreeSet::new());
+        hashes.record(&tree("a = Long sword\nb = Shield\n"), &target, Some("2026.1.2"), &BTreeSet::new());
+        hashes.record(&tree("a = Long sword\nb = Round shield\n"), &target, Some("2026.1.3"), &BTreeSet::new());
+
+        let report = hashes.check(&tree("a = Long sword\nb = Round shield\nc = Bow\n"), &target);
+        assert_eq!((report.tracked, report.untracked), (2, 1));
+        let stale: Vec<(&str, &str, Option<&str>)> = report
+            .stale
+            .iter()
+            .map(|entry| (entry.key.as_str(), entry.diff.as_str(), entry.since_build.as_deref()))
+            .collect();
+        assert_eq!(
+            stale,
+            vec![
+                ("a", "[-Sword-]{+Long sword+}", Some("2026.1.2")),
+                ("b", "[-Shield-]{+Round shield+}", Some("2026.1.3")),
+            ]
+        );
+        assert_eq!(report.stale[0].old.as_deref(), Some("Sword"));
+    }
+
+    #[test]
+    fn accepts_keys_and_records_dir() {
+        let dir = std::env::temp_dir().join(format!("hytaleru_stale_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let (root, game) = (dir.join("pack"), dir.join("game"));
+        let source = crate::lang::game_lang_dir(&game, crate::lang::SOURCE_LOCALE);
+        fs::create_dir_all(&source).unwrap();
+        fs::create_dir_all(&root).unwrap();
+        fs::write(game.join("version.txt"), "2026.1.1\n").unwrap();
+        fs::write(source.join("items.lang"), "a = Sword\nb = Shield\n").unwrap();
+        fs::write(root.join("items.lang"), "a = Меч\nb = Щит\n").unwrap();
+
+        let report = record_dir(&root, &game, &BTreeSet::new()).unwrap();
+        assert_eq!((report.game_build.as_deref(), report.tracked), (Some("2026.1.1"), 2));
+        assert!(report.stale.is_empty());
+
+        fs::write(game.join("version.txt"), "2026.1.2\n").unwrap();
+        fs::write(source.join("items.lang"), "a = Long sword\nb = Round shield\n").unwrap();
+        let accept = BTreeSet::from([accept_key("items.lang", "a")]);
+        assert_eq!(accept_key("items.lang", "a"), "items.lang:a");
+        let report = record_dir(&root, &game, &accept).unwrap();
+        let stale: Vec<&str> = report.stale.iter().map(|entry| entry.key.as_str()).collect();
+        assert_eq!(stale, vec!["b"]);
+        assert_eq!(report.stale[0].since_build.as_deref(), Some("2026.1.2"));
+
+        let saved = SourceHashes::read(&root.join(SOURCE_HASHES_FILE)).unwrap();
+        assert_eq!(saved.builds.len(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,340 @@
+use super::lint::translation_dir;
+use super::message::text_ranges;
+use super::{list_lang_files, LangFile, LangItem};
+use crate::settings::load_settings;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const PROTECTED: char = '\u{fffc}';
+const NBSP: char = '\u{a0}';
+const DASHES: [&str; 4] = ["--", "-", "–", "—"];
+const SHORT_WORDS: [&str; 20] = [
+    "а", "в", "во", "и", "к", "ко", "о", "об", "с", "со", "у", "на", "по", "за", "из", "от", "до", "не", "ни", "но",
+];
+const UNITS: [&str; 18] = [
+    "мм", "см", "м", "км", "г", "кг", "т", "мс", "с", "сек", "мин", "ч", "Гц", "КБ", "МБ", "ГБ", "px", "fps",
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum YoPolicy {
+    #[default]
+    Keep,
+    E,
+    Yo,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct TypographyConfig {
+    pub quotes: bool,
+    pub dashes: bool,
+    pub nbsp: bool,
+    pub ellipsis: bool,
+    pub yo: YoPolicy,
+    pub yo_words: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TypographyChange {
+    pub file: String,
+    pub key: String,
+    pub line: usize,
+    pub before: String,
+    pub after: String,
+}
+
+impl Default for TypographyConfig {
+    fn default() -> Self {
+        TypographyConfig {
+            quotes: true,
+            dashes: true,
+            nbsp: true,
+            ellipsis: true,
+            yo: YoPolicy::Keep,
+            yo_words: ["ещё", "её", "моё", "твоё", "своё", "объём", "счёт", "учёт"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn check_typography(path: String, rewrite: bool) -> Result<Vec<TypographyChange>, String> {
+    typography_dir(&translation_dir(Path::new(&path)), &load_settings()?.typography, rewrite)
+}
+
+pub fn typography_dir(dir: &Path, config: &TypographyConfig, rewrite: bool) -> Result<Vec<TypographyChange>, String> {
+    let mut changes = Vec::new();
+
+    for file in list_lang_files(dir)? {
+        let path = dir.join(&file);
+        let mut lang = LangFile::read(&path)?;
+        let mut changed = false;
+
+        for item in &mut lang.items {
+            let LangItem::Entry(entry) = item else {
+                continue;
+            };
+            let Some(after) = normalize_value(&entry.value, config) else {
+                continue;
+            };
+
+            changes.push(TypographyChange {
+                file: file.clone(),
+                key: entry.key.clone(),
+                line: entry.line,
+                before: entry.value.clone(),
+                after: after.clone(),
+            });
+            if rewrite {
+                entry.set_value(&after);
+                changed = true;
+            }
+        }
+
+        if changed {
+            lang.write(&path)?;
+        }
+    }
+
+    Ok(changes)
+}
+
+pub fn normalize_value(value: &str, config: &TypographyConfig) -> Option<String> {
+    if value.contains(PROTECTED) || !value.chars().any(is_cyrillic) {
+        return None;
+    }
+
+    let mut masked = String::with_capacity(value.len());
+    let mut protected = Vec::new();
+    let mut cursor = 0;
+    for range in text_ranges(value).ok()? {
+        if cursor < range.start {
+            protected.push(&value[cursor..range.start]);
+            masked.push(PROTECTED);
+        }
+        masked.push_str(&value[range.clone()]);
+        cursor = range.end;
+    }
+    if cursor < value.len() {
+        protected.push(&value[cursor..]);
+        masked.push(PROTECTED);
+    }
+
+    let mut text = masked;
+    if config.ellipsis {
+        text = text.replace("...", "…");
+    }
+    if config.dashes {
+        text = normalize_dashes(&text);
+    }
+    if config.quotes {
+        if let Some(quoted) = normalize_quotes(&text) {
+            text = quoted;
+        }
+    }
+    if config.nbsp {
+        text = add_nbsp(&text);
+    }
+    text = apply_yo(&text, config);
+
+    let mut result = String::with_capacity(text.len());
+    let mut protected = protected.into_iter();
+    for c in text.chars() {
+        if c == PROTECTED {
+            result.push_str(protected.next()?);
+        } else {
+            result.push(c);
+        }
+    }
+
+    (result != value).then_some(result)
+}
+
+fn is_cyrillic(c: char) -> bool {
+    matches!(c, '\u{0400}'..='\u{04FF}')
+}
+
+fn normalize_dashes(text: &str) -> String {
+    let mut result = text.to_string();
+    for dash in DASHES {
+        for space in [' ', NBSP] {
+            result = result.replace(&format!("{}{} ", space, dash), &format!("{}— ", NBSP));
+        }
+    }
+    result
+}
+
+fn normalize_quotes(text: &str) -> Option<String> {
+    if !text.contains(['"', '«', '»', '„', '“', '”']) {
+        return None;
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut depth = 0;
+    let mut previous: Option<char> = None;
+
+    for c in text.chars() {
+        let opening = match c {
+            '«' | '„' => Some(true),
+            '»' | '”' => Some(false),
+            '"' | '“' => Some(previous.is_none_or(|p| p.is_whitespace() || "([{—–-/«„".contains(p))),
+            _ => None,
+        };
+
+        match opening {
+            Some(true) => {
+                result.push(if depth == 0 { '«' } else { '„' });
+                depth += 1;
+            }
+            Some(false) => {
+                if depth == 0 {
+                    return None;
+                }
+                depth -= 1;
+                result.push(if depth == 0 { '»' } else { '“' });
+            }
+            None => result.push(c),
+        }
+        previous = result.chars().next_back();
+    }
+
+    (depth == 0).then_some(result)
+}
+
+fn add_nbsp(text: &str) -> String {
+    let mut chars: Vec<char> = text.chars().collect();
+
+    for i in 0..chars.len() {
+        if chars[i] != ' ' {
+            continue;
+        }
+
+        let mut start = i;
+        while start > 0 && chars[start - 1].is_alphabetic() {
+            start -= 1;
+        }
+        let word: String = chars[start..i].iter().collect::<String>().to_lowercase();
+        let bounded = start == 0 || !(chars[start - 1].is_alphanumeric() || chars[start - 1] == PROTECTED);
+        if bounded && SHORT_WORDS.contains(&word.as_str()) {
+            chars[i] = NBSP;
+            continue;
+        }
+
+        if i > 0 && chars[i - 1].is_ascii_digit() {
+            let unit: String = chars[i + 1..].iter().take_while(|c| c.is_alphabetic()).collect();
+            if UNITS.contains(&unit.as_str()) {
+                chars[i] = NBSP;
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+fn apply_yo(text: &str, config: &TypographyConfig) -> String {
+    match config.yo {
+        YoPolicy::Keep => text.to_string(),
+        YoPolicy::E => text.replace('ё', "е").replace('Ё', "Е"),
+        YoPolicy::Yo => {
+            let mut result = String::with_capacity(text.len());
+            let mut word = String::new();
+            for c in text.chars().chain(std::iter::once(' ')) {
+                if c.is_alphabetic() {
+                    word.push(c);
+                    continue;
+                }
+                result.push_str(&restore_yo(&word, &config.yo_words));
+                word.clear();
+                result.push(c);
+            }
+            result.pop();
+            result
+        }
+    }
+}
+
+fn restore_yo(word: &str, dictionary: &[String]) -> String {
+    let plain = word.to_lowercase().replace('ё', "е");
+    let Some(target) = dictionary
+        .iter()
+        .find(|entry| entry.to_lowercase().replace('ё', "е") == plain)
+    else {
+        return word.to_string();
+    };
+
+    word.chars()
+        .zip(target.to_lowercase().chars())
+        .map(|(original, replacement)| {
+            if original.is_uppercase() {
+                replacement.to_uppercase().next().unwrap_or(replacement)
+            } else {
+                replacement
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(value: &str) -> String {
+        normalize_value(value, &TypographyConfig::default()).unwrap_or_else(|| value.to_string())
+    }
+
+    #[test]
+    fn masks_placeholders_and_markup() {
+        assert_eq!(normalize("<b>Меч</b> - {name}..."), "<b>Меч</b>\u{a0}— {name}…");
+        assert_eq!(normalize("{count, plural, one {# \"шт\"} other {#}}"), "{count, plural, one {# «шт»} other {#}}");
+        assert_eq!(normalize("<color=\"red\">и</color> {a} - {b}"), "<color=\"red\">и</color> {a}\u{a0}— {b}");
+        assert!(normalize_value("Sword - \"x\"...", &TypographyConfig::default()).is_none());
+    }
+
+    #[test]
+    fn nests_quotes() {
+        assert_eq!(normalize("Он сказал \"это \"Меч\" героя\""), "Он сказал «это „Меч“ героя»");
+        assert_eq!(normalize("«Меч»"), "«Меч»");
+        assert_eq!(normalize("Меч \"героя"), "Меч \"героя");
+    }
+
+    #[test]
+    fn replaces_dashes_and_ellipsis() {
+        assert_eq!(normalize("Меч - оружие"), "Меч\u{a0}— оружие");
+        assert_eq!(normalize("Меч -- оружие"), "Меч\u{a0}— оружие");
+        assert_eq!(normalize("Меч – оружие"), "Меч\u{a0}— оружие");
+        assert_eq!(normalize("северо-запад"), "северо-запад");
+        assert_eq!(normalize("Загрузка..."), "Загрузка…");
+    }
+
+    #[test]
+    fn binds_short_words_and_units() {
+        assert_eq!(normalize("Иди в лес и к реке"), "Иди в\u{a0}лес и\u{a0}к\u{a0}реке");
+        assert_eq!(normalize("В лесу"), "В\u{a0}лесу");
+        assert_eq!(normalize("Высота 10 м и 5 кг"), "Высота 10\u{a0}м и\u{a0}5\u{a0}кг");
+        assert_eq!(normalize("Уровень 10 монет"), "Уровень 10 монет");
+        assert_eq!(normalize("Зов севера"), "Зов севера");
+    }
+
+    #[test]
+    fn applies_yo_policy() {
+        let mut config = TypographyConfig {
+            quotes: false,
+            dashes: false,
+            nbsp: false,
+            ellipsis: false,
+            ..TypographyConfig::default()
+        };
+        assert_eq!(normalize_value("Ещё её счёт", &config), None);
+
+        config.yo = YoPolicy::E;
+        assert_eq!(normalize_value("Ещё её счёт", &config).as_deref(), Some("Еще ее счет"));
+
+        config.yo = YoPolicy::Yo;
+        assert_eq!(normalize_value("Еще ее счет и лес", &config).as_deref(), Some("Ещё её счёт и лес"));
+        assert_eq!(normalize_value("ЕЩЕ", &config).as_deref(), Some("ЕЩЁ"));
+        assert_eq!(normalize_value("Весело", &config), None);
+    }
+}
@@ -0,0 +1,151 @@
+use super::message::{argument_names, markup_tags, parse_message};
+use super::{game_lang_dir, pack_lang_tree, read_lang_tree, LangFile, SOURCE_LOCALE};
+use crate::gamepath::hytale_game_dir;
+use crate::settings::{load_settings, CompatibilityPolicy};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+const REPORTED_PROBLEMS: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProblemKind {
+    Syntax,
+    Placeholders,
+    LineBreaks,
+    Markup,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeyProblem {
+    pub file: String,
+    pub key: String,
+    pub kind: ProblemKind,
+    pub message: String,
+}
+
+#[tauri::command]
+pub fn validate_translation() -> Result<Vec<KeyProblem>, String> {
+    let game = hytale_game_dir()?;
+    let source = read_lang_tree(&game_lang_dir(&game, SOURCE_LOCALE))?;
+    Ok(validate_trees(&source, &pack_lang_tree(&game)?))
+}
+
+pub fn ensure_valid_strings(game: &Path, target: &BTreeMap<String, LangFile>) -> Result<Vec<KeyProblem>, String> {
+    let source = read_lang_tree(&game_lang_dir(game, SOURCE_LOCALE))?;
+    let problems = validate_trees(&source, target);
+    if problems.is_empty() || load_settings()?.validation_policy != CompatibilityPolicy::Block {
+        return Ok(problems);
+    }
+
+    let mut lines: Vec<String> = problems
+        .iter()
+        .take(REPORTED_PROBLEMS)
+        .map(|problem| format!("{}: {}: {}", problem.file, problem.key, problem.message))
+        .collect();
+    if problems.len() > REPORTED_PROBLEMS {
+        lines.push(format!("и ещё {}", problems.len() - REPORTED_PROBLEMS));
+    }
+    Err(format!("Ошибки в строках перевода:\n{}", lines.join("\n")))
+}
+
+pub fn validate_trees(source: &BTreeMap<String, LangFile>, target: &BTreeMap<String, LangFile>) -> Vec<KeyProblem> {
+    let mut problems = Vec::new();
+
+    for (file, lang) in target {
+        let Some(source_file) = source.get(file) else {
+            continue;
+        };
+        let originals: BTreeMap<&str, &str> = source_file
+            .entries()
+            .map(|entry| (entry.key.as_str(), entry.value.as_str()))
+            .collect();
+
+        for entry in lang.entries() {
+            if let Some(original) = originals.get(entry.key.as_str()) {
+                for (kind, message) in compare_values(original, &entry.value) {
+                    problems.push(KeyProblem {
+                        file: file.clone(),
+                        key: entry.key.clone(),
+                        kind,
+                        message,
+                    });
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+pub fn compare_values(original: &str, translated: &str) -> Vec<(ProblemKind, String)> {
+    let mut problems = Vec::new();
+
+    let expected = original.matches('\n').count();
+    let found = translated.matches('\n').count();
+    if expected != found {
+        problems.push((
+            ProblemKind::LineBreaks,
+            format!("переводов строки \\n в оригинале {}, в переводе {}", expected, found),
+        ));
+    }
+
+    let translated_parts = match parse_message(translated) {
+        Ok(parts) => parts,
+        Err(e) => {
+            problems.push((ProblemKind::Syntax, format!("ошибка синтаксиса сообщения: {}", e)));
+            return problems;
+        }
+    };
+    let Ok(original_parts) = parse_message(original) else {
+        return problems;
+    };
+
+    let expected = argument_names(&original_parts);
+    let found = argument_names(&translated_parts);
+    if expected != found {
+        problems.push((
+            ProblemKind::Placeholders,
+            format!(
+                "плейсхолдеры не совпадают{}{}",
+                describe("нет", expected.difference(&found).map(|name| format!("{{{}}}", name))),
+                describe("лишние", found.difference(&expected).map(|name| format!("{{{}}}", name)))
+            ),
+        ));
+    }
+
+    let expected = markup_tags(&original_parts);
+    let found = markup_tags(&translated_parts);
+    if expected != found {
+        let missing: BTreeSet<&String> = expected
+            .iter()
+            .filter(|(tag, count)| found.get(*tag).unwrap_or(&0) < count)
+            .map(|(tag, _)| tag)
+            .collect();
+        let extra: BTreeSet<&String> = found
+            .iter()
+            .filter(|(tag, count)| expected.get(*tag).unwrap_or(&0) < count)
+            .map(|(tag, _)| tag)
+            .collect();
+        problems.push((
+            ProblemKind::Markup,
+            format!(
+                "разметка не совпадает{}{}",
+                describe("не хватает", missing.into_iter().cloned()),
+                describe("лишние", extra.into_iter().cloned())
+            ),
+        ));
+    }
+
+    problems
+}
+
+fn describe(label: &str, names: impl Iterator<Item = String>) -> String {
+    let names: Vec<String> = names.collect();
+    if names.is_empty() {
+        String::new()
+    } else {
+        format!("; {}: {}", label, names.join(", "))
+    }
+}
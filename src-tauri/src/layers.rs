@@ -0,0 +1,151 @@
+use crate::dependencies::resolve_plan;
+use crate::manifest::PACK_LOCALE;
+use crate::providers::provider_or_active;
+use crate::settings::{load_settings, save_settings};
+use crate::lang::{list_lang_files, LangFile};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const BASE_LAYER: &str = "base";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TranslationLayer {
+    pub name: String,
+    pub path: String,
+    pub priority: u32,
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KeyOrigin {
+    pub file: String,
+    pub key: String,
+    pub layer: String,
+    pub value: String,
+    pub overridden: Vec<String>,
+}
+
+#[tauri::command]
+pub fn list_translation_layers() -> Result<Vec<TranslationLayer>, String> {
+    Ok(load_settings()?.translation_layers)
+}
+
+#[tauri::command]
+pub fn add_translation_layer(name: String, path: String, priority: u32) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name == BASE_LAYER {
+        return Err("Недопустимое имя слоя".to_string());
+    }
+
+    if priority == 0 {
+        return Err("Приоритет слоя должен быть больше нуля".to_string());
+    }
+
+    if !Path::new(&path).is_dir() {
+        return Err("Папка слоя не найдена".to_string());
+    }
+
+    let mut settings = load_settings()?;
+    settings.translation_layers.retain(|layer| layer.name != name);
+    settings.translation_layers.push(TranslationLayer {
+        name,
+        path,
+        priority,
+        enabled: true,
+    });
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn remove_translation_layer(name: String) -> Result<(), String> {
+    let mut settings = load_settings()?;
+    settings.translation_layers.retain(|layer| layer.name != name);
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn update_translation_layer(name: String, priority: u32, enabled: bool) -> Result<(), String> {
+    if priority == 0 {
+        return Err("Приоритет слоя должен быть больше нуля".to_string());
+    }
+
+    let mut settings = load_settings()?;
+    let layer = settings
+        .translation_layers
+        .iter_mut()
+        .find(|layer| layer.name == name)
+        .ok_or("Слой не найден")?;
+    layer.priority = priority;
+    layer.enabled = enabled;
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn explain_translation(key: Option<String>, provider: Option<String>) -> Result<Vec<KeyOrigin>, String> {
+    let plan = resolve_plan(&provider_or_active(provider)?.id)?;
+    let mut sources: Vec<(String, PathBuf)> = plan
+        .order
+        .into_iter()
+        .map(|pack| (pack.id, pack.dir.join("Language").join(PACK_LOCALE)))
+        .collect();
+    sources.extend(
+        active_layers()?
+            .into_iter()
+            .map(|layer| (layer.name, PathBuf::from(layer.path))),
+    );
+
+    let mut origins: BTreeMap<(String, String), KeyOrigin> = BTreeMap::new();
+    for (layer, root) in &sources {
+        for file in list_lang_files(root)? {
+            let lang = LangFile::read(&root.join(&file))?;
+
+            for entry in lang.entries() {
+                if key.as_deref().is_some_and(|filter| !entry.key.contains(filter)) {
+                    continue;
+                }
+
+                let value = entry.value.clone();
+                let origin = origins
+                    .entry((file.clone(), entry.key.clone()))
+                    .or_insert_with(|| KeyOrigin {
+                        file: file.clone(),
+                        key: entry.key.clone(),
+                        layer: layer.clone(),
+                        value: value.clone(),
+                        overridden: Vec::new(),
+                    });
+                if origin.layer != *layer {
+                    let previous = std::mem::replace(&mut origin.layer, layer.clone());
+                    origin.overridden.push(previous);
+                    origin.value = value;
+                }
+            }
+        }
+    }
+
+    Ok(origins.into_values().collect())
+}
+
+pub fn apply_layers(tree: &mut BTreeMap<String, LangFile>) -> Result<(), String> {
+    for layer in active_layers()? {
+        let root = PathBuf::from(&layer.path);
+        for file in list_lang_files(&root)? {
+            let overrides = LangFile::read(&root.join(&file))
+                .map_err(|e| format!("Ошибка чтения слоя {}: {}", layer.name, e))?;
+            tree.entry(file).or_default().merge(&overrides);
+        }
+    }
+    Ok(())
+}
+
+fn active_layers() -> Result<Vec<TranslationLayer>, String> {
+    let mut layers: Vec<TranslationLayer> = load_settings()?
+        .translation_layers
+        .into_iter()
+        .filter(|layer| layer.enabled && Path::new(&layer.path).is_dir())
+        .collect();
+    layers.sort_by_key(|layer| layer.priority);
+    Ok(layers)
+}
@@ -1,5 +1,7 @@
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn resolve_assets_dir() -> Result<PathBuf, String> {
     if let Ok(path) = std::env::var("HYTALERU_ASSETS_DIR") {
@@ -50,11 +52,22 @@ pub fn resolve_assets_dir() -> Result<PathBuf, String> {
     Err("Не удалось найти директорию assets".to_string())
 }
 
-pub fn get_config_path() -> Result<PathBuf, String> {
+pub fn get_config_dir() -> Result<PathBuf, String> {
     let app_data = dirs::config_dir().ok_or("Cannot find config dir")?;
     let config_dir = app_data.join("HytaleRuLoader");
     fs::create_dir_all(&config_dir).map_err(|e| e.to_string())?;
-    Ok(config_dir.join("config.txt"))
+    Ok(config_dir)
+}
+
+pub fn get_config_path() -> Result<PathBuf, String> {
+    Ok(get_config_dir()?.join("config.txt"))
+}
+
+pub fn get_data_dir() -> Result<PathBuf, String> {
+    let app_data = dirs::data_dir().ok_or("Cannot find data dir")?;
+    let data_dir = app_data.join("HytaleRuLoader");
+    fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+    Ok(data_dir)
 }
 
 pub fn save_to_config(value: &str) -> Result<(), String> {
@@ -84,3 +97,87 @@ pub fn remove_config() {
         let _ = fs::remove_file(path);
     }
 }
+
+pub fn unix_timestamp() -> Result<u64, String> {
+    Ok(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| "Ошибка времени системы")?
+        .as_secs())
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+pub fn prepare_dir(path: &Path) -> Result<(), String> {
+    if path.exists() {
+        fs::remove_dir_all(path)
+            .map_err(|e| format!("Ошибка очистки директории: {}", e))?;
+    }
+    fs::create_dir_all(path)
+        .map_err(|e| format!("Ошибка создания директории: {}", e))?;
+    Ok(())
+}
+
+pub fn replace_dir(src: &Path, dst: &Path) -> Result<(), String> {
+    if dst.exists() {
+        fs::remove_dir_all(dst)
+            .map_err(|e| format!("Ошибка удаления директории: {}", e))?;
+    }
+
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Ошибка создания директории: {}", e))?;
+    }
+
+    if fs::rename(src, dst).is_err() {
+        copy_dir_recursive(src, dst)?;
+        fs::remove_dir_all(src)
+            .map_err(|e| format!("Ошибка удаления временной директории: {}", e))?;
+    }
+
+    Ok(())
+}
+
+pub fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
+    fs::create_dir_all(dst)
+        .map_err(|e| format!("Ошибка создания директории: {}", e))?;
+
+    for entry in fs::read_dir(src)
+        .map_err(|e| format!("Ошибка чтения директории: {}", e))?
+    {
+        let entry = entry.map_err(|e| format!("Ошибка чтения записи: {}", e))?;
+        let entry_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if entry_path.is_dir() {
+            copy_dir_recursive(&entry_path, &dst_path)?;
+        } else {
+            fs::copy(&entry_path, &dst_path)
+                .map_err(|e| format!("Ошибка копирования файла: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    let temp_path = path.with_extension(format!(
+        "tmp_{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| "Ошибка времени системы")?
+            .as_millis()
+    ));
+
+    if let Some(parent) = temp_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Ошибка создания директории: {}", e))?;
+    }
+
+    fs::write(&temp_path, bytes)
+        .map_err(|e| format!("Ошибка записи файла: {}", e))?;
+    fs::rename(&temp_path, path)
+        .map_err(|e| format!("Ошибка сохранения файла: {}", e))?;
+    Ok(())
+}
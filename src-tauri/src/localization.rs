@@ -1,71 +1,87 @@
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
+use crate::dependencies::resolve_plan;
+use crate::game_build::ensure_compatible;
 use crate::gamepath::hytale_game_dir;
-use hytaleru_lib::resolve_assets_dir;
+use crate::lang::fallback::fill_missing_keys;
+use crate::lang::validate::{ensure_valid_strings, KeyProblem};
+use crate::lang::{game_lang_dir, plan_lang_tree, SOURCE_LOCALE};
+use crate::layers::apply_layers;
+use crate::pack_store::original_fonts_dir;
+use crate::providers::provider_for_game;
+use crate::settings::load_settings;
+use hytaleru_lib::copy_dir_recursive;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct InstallReport {
+    pub warnings: Vec<String>,
+    pub problems: Vec<KeyProblem>,
+}
 
 fn restore_original(game: &PathBuf) -> Result<(), String> {
-    let assets_dir = resolve_assets_dir()?;
-    let original_fonts = assets_dir.join("Fonts").join("original");
+    let original_fonts = original_fonts_dir().ok_or("Оригинальные шрифты не найдены")?;
     let fonts = game.join("Client/Data/Shared/Fonts");
 
-    if !original_fonts.exists() {
-        return Err("Оригинальные шрифты не найдены".to_string());
-    }
-
     fs::create_dir_all(&fonts).map_err(|e| e.to_string())?;
     copy_dir_recursive(&original_fonts, &fonts)?;
 
     Ok(())
 }
 
-fn install_ru(game: &PathBuf) -> Result<(), String> {
-    let assets_dir = resolve_assets_dir()?;
+pub fn install_ru(game: &PathBuf) -> Result<InstallReport, String> {
+    let plan = resolve_plan(&provider_for_game(game)?.id)?;
     let exe_path = game.join("Client/HytaleClient.exe");
     if !exe_path.exists() {
         return Err("HytaleClient.exe не найден. Проверьте путь к игре.".to_string());
     }
 
+    if !plan.conflicts.is_empty() {
+        return Err(format!("Конфликт зависимостей: {}", plan.conflicts.join("; ")));
+    }
+
     let fonts = game.join("Client/Data/Shared/Fonts");
     let lang  = game.join("Client/Data/Shared/Language/ru-RU");
-    let ru_fonts = assets_dir.join("Fonts").join("withRU");
-    let ru_lang = assets_dir.join("Language").join("ru-RU");
+    let ru_fonts: Vec<PathBuf> = plan.order.iter().map(|pack| pack.dir.join("Fonts").join("withRU")).collect();
+    let ru_lang: Vec<PathBuf> = plan.order.iter().map(|pack| pack.dir.join("Language").join("ru-RU")).collect();
 
-    if !ru_fonts.exists() {
+    if !ru_fonts.iter().any(|dir| dir.exists()) {
         return Err("Папка Fonts/withRU не найдена".to_string());
     }
 
-    if !ru_lang.exists() {
+    if !ru_lang.iter().any(|dir| dir.exists()) {
         return Err("Папка Language/ru-RU не найдена".to_string());
     }
 
+    let mut report = InstallReport::default();
+    for pack in &plan.order {
+        report.warnings.extend(ensure_compatible(&pack.manifest, game)?);
+    }
+    let mut strings = plan_lang_tree(&plan)?;
+    apply_layers(&mut strings)?;
+    report.problems = ensure_valid_strings(game, &strings)?;
+
     // гарантируем папки
     fs::create_dir_all(&fonts).map_err(|e| e.to_string())?;
-    copy_dir_recursive(&ru_fonts, &fonts)?;
+    for dir in ru_fonts.iter().filter(|dir| dir.exists()) {
+        copy_dir_recursive(dir, &fonts)?;
+    }
 
     if lang.exists() {
         fs::remove_dir_all(&lang).map_err(|e| e.to_string())?;
     }
-    copy_dir_recursive(&ru_lang, &lang)?;
-
-    Ok(())
-}
-
-fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
-    fs::create_dir_all(dst).map_err(|e| e.to_string())?;
-
-    for entry in fs::read_dir(src).map_err(|e| e.to_string())? {
-        let entry = entry.map_err(|e| e.to_string())?;
-        let entry_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
+    for dir in ru_lang.iter().filter(|dir| dir.exists()) {
+        copy_dir_recursive(dir, &lang)?;
+    }
+    for (file, content) in &strings {
+        content.write(&lang.join(file))?;
+    }
 
-        if entry_path.is_dir() {
-            copy_dir_recursive(&entry_path, &dst_path)?;
-        } else {
-            fs::copy(&entry_path, &dst_path).map_err(|e| e.to_string())?;
-        }
+    if load_settings()?.fill_missing_from_english {
+        fill_missing_keys(&game_lang_dir(game, SOURCE_LOCALE), &lang)?;
     }
 
-    Ok(())
+    Ok(report)
 }
 
 #[tauri::command]
@@ -76,7 +92,7 @@ pub fn check_ru_exists() -> Result<bool, String> {
 }
 
 #[tauri::command]
-pub fn install_ru_cmd() -> Result<(), String> {
+pub fn install_ru_cmd() -> Result<InstallReport, String> {
     let game = hytale_game_dir()?;
     install_ru(&game)
 }
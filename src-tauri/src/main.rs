@@ -3,8 +3,22 @@ use std::path::PathBuf;
 use std::fs;
 #[allow(non_snake_case)]
 mod APIs;
+mod cli;
+mod dependencies;
 mod localization;
 mod gamepath;
+mod game_build;
+mod lang;
+mod layers;
+mod manifest;
+mod network;
+mod pack_archive;
+mod pack_builder;
+mod pack_store;
+mod profiles;
+mod providers;
+mod settings;
+mod version;
 
 use hytaleru_lib::save_to_config;
 use hytaleru_lib::load_from_config;
@@ -111,6 +125,11 @@ fn find_game_automatically() -> Result<Option<String>, String> {
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_opener::init())
@@ -121,6 +140,47 @@ fn main() {
             localization::check_ru_exists,
             localization::remove_ru_cmd,
 
+            pack_store::list_localization_versions,
+            pack_store::switch_localization_version,
+            pack_store::reset_localization_selection,
+            pack_store::rollback_localization,
+            pack_store::compare_localization_versions,
+            pack_store::select_compatible_version,
+
+            profiles::list_game_profiles,
+            profiles::add_game_profile,
+            profiles::remove_game_profile,
+            profiles::apply_to_installed_profiles,
+            providers::list_providers,
+            providers::add_provider,
+            providers::remove_provider,
+            providers::set_active_provider,
+            providers::set_profile_provider,
+
+            dependencies::list_packs,
+            dependencies::set_pack_enabled,
+            dependencies::get_install_plan,
+            layers::list_translation_layers,
+            layers::add_translation_layer,
+            layers::remove_translation_layer,
+            layers::update_translation_layer,
+            layers::explain_translation,
+            lang::coverage::get_translation_coverage,
+            lang::coverage::export_translation_coverage,
+            lang::exchange::export_translation,
+            lang::exchange::import_translation,
+            lang::validate::validate_translation,
+            lang::lint::lint_translation,
+            lang::typography::check_typography,
+            lang::glossary::check_glossary,
+            lang::stale::check_stale_translations,
+            lang::stale::record_source_hashes,
+            game_build::get_game_build,
+            game_build::check_pack_compatibility,
+
+            settings::get_app_settings,
+            settings::save_app_settings,
+
             get_current_game_path,
             save_custom_path,
             validate_custom_path,
@@ -135,7 +195,11 @@ fn main() {
 
             APIs::lang_updater::check_localization_updates,
             APIs::lang_updater::auto_update_localization,
-            APIs::lang_updater::download_localization_update
+            APIs::lang_updater::download_localization_update,
+            APIs::lang_updater::install_provider,
+            APIs::lang_updater::inspect_local_pack,
+            APIs::lang_updater::install_local_pack,
+            pack_builder::build_localization_pack
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
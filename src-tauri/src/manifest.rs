@@ -0,0 +1,277 @@
+use crate::version::{Version, VersionReq};
+use hytaleru_lib::sha256_hex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const MANIFEST_VERSION: u32 = 2;
+pub const PACK_LOCALE: &str = "ru-RU";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PackManifest {
+    pub group: Option<String>,
+    pub name: Option<String>,
+    pub version: String,
+    pub description: Option<String>,
+    pub authors: Vec<ManifestAuthor>,
+    pub website: Option<String>,
+    pub server_version: Option<String>,
+    pub dependencies: BTreeMap<String, String>,
+    pub optional_dependencies: BTreeMap<String, String>,
+    pub disabled_by_default: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_version: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<PackComponent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<ManifestFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_app_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_builds: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PackComponent {
+    Fonts,
+    ClientStrings,
+    ServerStrings,
+    AvatarCustomization,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ManifestFile {
+    pub path: String,
+    pub sha256: String,
+    pub size: u64,
+    pub component: Option<PackComponent>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ManifestAuthor {
+    pub name: String,
+    pub email: Option<String>,
+    pub url: Option<String>,
+}
+
+impl PackManifest {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let manifest: PackManifest = serde_json::from_slice(bytes)
+            .map_err(|e| format!("Ошибка парсинга manifest.json: {}", e))?;
+
+        if manifest.version.trim().is_empty() {
+            return Err("Поле Version не найдено в manifest.json".to_string());
+        }
+
+        match manifest.schema_version() {
+            1 => {}
+            MANIFEST_VERSION => manifest.validate_schema()?,
+            other => return Err(format!("Неподдерживаемая версия схемы manifest.json: {}", other)),
+        }
+
+        Ok(manifest)
+    }
+
+    pub fn read_from(dir: &Path) -> Result<Self, String> {
+        let bytes = fs::read(dir.join("manifest.json"))
+            .map_err(|e| format!("Ошибка чтения manifest.json: {}", e))?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn parsed_version(&self) -> Result<Version, String> {
+        Version::parse(&self.version)
+    }
+
+    pub fn id(&self) -> Option<String> {
+        let name = self.name.as_deref().map(str::trim).filter(|name| !name.is_empty())?;
+        match self.group.as_deref().map(str::trim).filter(|group| !group.is_empty()) {
+            Some(group) => Some(format!("{}:{}", group, name)),
+            None => Some(name.to_string()),
+        }
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        self.manifest_version.unwrap_or(1)
+    }
+
+    pub fn is_partial(&self) -> bool {
+        let fonts = self.components.contains(&PackComponent::Fonts);
+        let strings = self.components.iter().any(|component| *component != PackComponent::Fonts);
+        !self.dependencies.is_empty() || (!self.components.is_empty() && (!fonts || !strings))
+    }
+
+    pub fn supported_builds(&self) -> Result<VersionReq, String> {
+        let declared = self.supported_builds.as_deref().or(self.server_version.as_deref());
+        match declared.map(str::trim) {
+            None | Some("") => Ok(VersionReq::any()),
+            Some(value) => VersionReq::parse(value),
+        }
+    }
+
+    pub fn check_app_version(&self) -> Result<(), String> {
+        let Some(min) = self.min_app_version.as_deref() else {
+            return Ok(());
+        };
+
+        let app = Version::parse(env!("CARGO_PKG_VERSION"))?;
+        if app < Version::parse(min)? {
+            return Err(format!(
+                "Пакет требует версию приложения {} или новее, установлена {}",
+                min, app
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn validate_contents(&self, pack_dir: &Path) -> Result<(), String> {
+        if self.schema_version() < MANIFEST_VERSION {
+            return Ok(());
+        }
+
+        let mut missing = Vec::new();
+        let mut mismatched = Vec::new();
+        for file in &self.files {
+            match fs::read(pack_dir.join(stored_path(&file.path)?)) {
+                Ok(bytes) if bytes.len() as u64 == file.size && sha256_hex(&bytes).eq_ignore_ascii_case(&file.sha256) => {}
+                Ok(_) => mismatched.push(file.path.clone()),
+                Err(_) => missing.push(file.path.clone()),
+            }
+        }
+
+        let declared: BTreeSet<&str> = self.files.iter().map(|file| file.path.as_str()).collect();
+        let extra: Vec<String> = pack_files(pack_dir)?
+            .into_iter()
+            .filter(|path| !declared.contains(path.as_str()))
+            .collect();
+
+        let mut problems = Vec::new();
+        if !missing.is_empty() {
+            problems.push(format!("нет файлов: {}", missing.join(", ")));
+        }
+        if !mismatched.is_empty() {
+            problems.push(format!("не совпадает хеш или размер: {}", mismatched.join(", ")));
+        }
+        if !extra.is_empty() {
+            problems.push(format!("лишние файлы: {}", extra.join(", ")));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Содержимое пакета не совпадает с manifest.json: {}", problems.join("; ")))
+        }
+    }
+
+    fn validate_schema(&self) -> Result<(), String> {
+        if self.locale.as_deref() != Some(PACK_LOCALE) {
+            return Err(format!("Поле Locale в manifest.json должно быть {}", PACK_LOCALE));
+        }
+
+        if self.files.is_empty() {
+            return Err("Поле Files в manifest.json не заполнено".to_string());
+        }
+
+        let mut paths = BTreeSet::new();
+        let mut used = BTreeSet::new();
+        for file in &self.files {
+            let component = PackComponent::for_path(&file.path)
+                .ok_or(format!("Неподдерживаемый путь в manifest.json: {}", file.path))?;
+            stored_path(&file.path)?;
+
+            if !paths.insert(file.path.as_str()) {
+                return Err(format!("Файл {} указан в manifest.json дважды", file.path));
+            }
+            if file.sha256.len() != 64 || !file.sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(format!("Некорректный SHA-256 для {}", file.path));
+            }
+            if file.component.is_some_and(|declared| declared != component) {
+                return Err(format!("Файл {} отнесён к неверному компоненту", file.path));
+            }
+            used.insert(component);
+        }
+
+        let declared: BTreeSet<PackComponent> = self.components.iter().copied().collect();
+        if declared != used {
+            return Err("Список Components в manifest.json не совпадает с файлами пакета".to_string());
+        }
+
+        if let Some(min) = &self.min_app_version {
+            Version::parse(min)?;
+        }
+        self.supported_builds()?;
+        Ok(())
+    }
+}
+
+impl PackComponent {
+    pub fn for_path(path: &str) -> Option<Self> {
+        if path.strip_prefix("Fonts/").is_some_and(|rest| !rest.is_empty()) {
+            return Some(PackComponent::Fonts);
+        }
+
+        let lang = path.strip_prefix("Language/ru-RU/").filter(|rest| !rest.is_empty())?;
+        if lang.starts_with("avatarCustomization/") {
+            Some(PackComponent::AvatarCustomization)
+        } else if lang.starts_with("server") {
+            Some(PackComponent::ServerStrings)
+        } else {
+            Some(PackComponent::ClientStrings)
+        }
+    }
+}
+
+pub fn stored_path(path: &str) -> Result<PathBuf, String> {
+    let safe = !path.is_empty()
+        && path
+            .split('/')
+            .all(|part| !part.is_empty() && part != "." && part != ".." && !part.contains('\\'));
+    if !safe {
+        return Err(format!("Небезопасный путь в пакете: {}", path));
+    }
+
+    if path == "manifest.json" {
+        return Ok(PathBuf::from(path));
+    }
+
+    if let Some(fonts_rel) = path.strip_prefix("Fonts/") {
+        return Ok(Path::new("Fonts").join("withRU").join(fonts_rel));
+    }
+
+    if path.starts_with("Language/ru-RU/") {
+        return Ok(PathBuf::from(path));
+    }
+
+    Err(format!("Неподдерживаемый путь в пакете: {}", path))
+}
+
+fn pack_files(pack_dir: &Path) -> Result<Vec<String>, String> {
+    let mut files = Vec::new();
+    for (stored, logical) in [("Fonts/withRU", "Fonts"), ("Language/ru-RU", "Language/ru-RU")] {
+        collect_files(&pack_dir.join(stored), logical, &mut files)?;
+    }
+    Ok(files)
+}
+
+fn collect_files(dir: &Path, logical: &str, files: &mut Vec<String>) -> Result<(), String> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir).map_err(|e| format!("Ошибка чтения директории: {}", e))? {
+        let entry = entry.map_err(|e| format!("Ошибка чтения записи: {}", e))?;
+        let path = entry.path();
+        let name = format!("{}/{}", logical, entry.file_name().to_string_lossy());
+        if path.is_dir() {
+            collect_files(&path, &name, files)?;
+        } else {
+            files.push(name);
+        }
+    }
+    Ok(())
+}
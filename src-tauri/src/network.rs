@@ -0,0 +1,74 @@
+use crate::settings::{load_settings, AppSettings};
+use std::fs;
+use std::time::Duration;
+
+pub const USER_AGENT: &str = "HytaleRU-App";
+
+pub fn build_client(default_timeout: Duration) -> Result<reqwest::Client, String> {
+    let settings = load_settings()?;
+    ensure_online_with(&settings)?;
+
+    let timeout = settings
+        .request_timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or(default_timeout);
+
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(timeout);
+
+    if let Some(connect_timeout) = settings.connect_timeout_secs {
+        builder = builder.connect_timeout(Duration::from_secs(connect_timeout));
+    }
+
+    if let Some(proxy_url) = proxy_url(&settings) {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(|e| format!("Некорректный адрес прокси: {}", e))?;
+        builder = builder.proxy(proxy);
+    }
+
+    for certificate in root_certificates(&settings)? {
+        builder = builder.add_root_certificate(certificate);
+    }
+
+    builder
+        .build()
+        .map_err(|e| format!("Ошибка инициализации клиента: {}", e))
+}
+
+pub fn root_certificates(settings: &AppSettings) -> Result<Vec<reqwest::Certificate>, String> {
+    settings
+        .extra_root_certificates
+        .iter()
+        .map(|path| {
+            let bytes = fs::read(path)
+                .map_err(|e| format!("Ошибка чтения сертификата {}: {}", path, e))?;
+            reqwest::Certificate::from_pem(&bytes)
+                .or_else(|_| reqwest::Certificate::from_der(&bytes))
+                .map_err(|e| format!("Некорректный сертификат {}: {}", path, e))
+        })
+        .collect()
+}
+
+pub fn is_offline() -> bool {
+    load_settings().map(|s| s.offline_mode).unwrap_or(false)
+}
+
+pub fn ensure_online() -> Result<(), String> {
+    ensure_online_with(&load_settings()?)
+}
+
+pub fn proxy_url(settings: &AppSettings) -> Option<&str> {
+    settings
+        .proxy_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+}
+
+fn ensure_online_with(settings: &AppSettings) -> Result<(), String> {
+    if settings.offline_mode {
+        return Err("Включён автономный режим: сетевые запросы отключены".to_string());
+    }
+    Ok(())
+}
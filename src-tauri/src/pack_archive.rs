@@ -0,0 +1,215 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path};
+
+const FULL_PREFIX: &str = "install/release/package/game/latest/Client/Data/Shared/";
+const SHARED_PREFIX: &str = "Client/Data/Shared/";
+const PACK_MARKERS: [&str; 3] = ["Fonts/", "Language/ru-RU/", "Language/ru_RU/"];
+const MAX_LISTED_ENTRIES: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    TarGz,
+    TarZst,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackLayout {
+    pub root: String,
+    pub description: &'static str,
+}
+
+impl ArchiveFormat {
+    pub fn detect(path: &Path) -> Result<Self, String> {
+        let mut magic = [0u8; 4];
+        let read = fs::File::open(path)
+            .and_then(|mut file| file.read(&mut magic))
+            .map_err(|e| format!("Ошибка открытия архива: {}", e))?;
+
+        match &magic[..read] {
+            [0x50, 0x4b, 0x03, 0x04] => Ok(ArchiveFormat::Zip),
+            [0x1f, 0x8b, ..] => Ok(ArchiveFormat::TarGz),
+            [0x28, 0xb5, 0x2f, 0xfd] => Ok(ArchiveFormat::TarZst),
+            _ => Err("Неподдерживаемый формат архива, ожидается .zip, .tar.gz или .tar.zst".to_string()),
+        }
+    }
+}
+
+impl PackLayout {
+    pub fn relative<'a>(&self, name: &'a str) -> Option<&'a str> {
+        name.strip_prefix(self.root.as_str())
+    }
+}
+
+pub fn for_each_file<F>(path: &Path, mut visit: F) -> Result<(), String>
+where
+    F: FnMut(&str, u64, &mut dyn Read) -> Result<(), String>,
+{
+    let file = fs::File::open(path).map_err(|e| format!("Ошибка открытия архива: {}", e))?;
+
+    match ArchiveFormat::detect(path)? {
+        ArchiveFormat::Zip => {
+            let mut archive = zip::ZipArchive::new(file)
+                .map_err(|e| format!("Ошибка чтения ZIP архива: {}", e))?;
+
+            for i in 0..archive.len() {
+                let mut entry = archive
+                    .by_index(i)
+                    .map_err(|e| format!("Ошибка чтения файла из архива: {}", e))?;
+
+                let symlink = entry
+                    .unix_mode()
+                    .is_some_and(|mode| (mode & 0o170000) == 0o120000);
+                if entry.is_dir() || symlink {
+                    continue;
+                }
+
+                let name = entry
+                    .enclosed_name()
+                    .ok_or("Небезопасный путь в архиве")?
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                let size = entry.size();
+                visit(&name, size, &mut entry)?;
+            }
+        }
+        ArchiveFormat::TarGz => visit_tar(flate2::read::GzDecoder::new(file), &mut visit)?,
+        ArchiveFormat::TarZst => {
+            let decoder = zstd::stream::read::Decoder::new(file)
+                .map_err(|e| format!("Ошибка чтения архива zstd: {}", e))?;
+            visit_tar(decoder, &mut visit)?
+        }
+    }
+
+    Ok(())
+}
+
+pub fn list_files(path: &Path) -> Result<Vec<String>, String> {
+    let mut names = Vec::new();
+    for_each_file(path, |name, _, _| {
+        names.push(name.to_string());
+        Ok(())
+    })?;
+    Ok(names)
+}
+
+pub fn detect_layout(names: &[String]) -> Result<PackLayout, String> {
+    let roots: BTreeSet<&str> = names.iter().filter_map(|name| pack_root(name)).collect();
+
+    let mut layouts: Vec<PackLayout> = roots
+        .iter()
+        .filter_map(|root| {
+            let (outer, description) = if let Some(outer) = root.strip_suffix(FULL_PREFIX) {
+                (outer, "полная структура игры")
+            } else if let Some(outer) = root.strip_suffix(SHARED_PREFIX) {
+                (outer, "Client/Data/Shared")
+            } else {
+                (*root, "файлы в корне архива")
+            };
+
+            let wrapped = outer.trim_end_matches('/').split('/').filter(|p| !p.is_empty()).count();
+            (wrapped <= 1).then(|| PackLayout {
+                root: root.to_string(),
+                description,
+            })
+        })
+        .collect();
+
+    match layouts.len() {
+        1 => Ok(layouts.remove(0)),
+        0 => Err(format!(
+            "Не удалось определить структуру архива: нет папок Fonts и Language/ru-RU. Найдено: {}",
+            summarize_entries(names)
+        )),
+        _ => Err(format!(
+            "В архиве несколько наборов файлов локализации: {}",
+            layouts
+                .iter()
+                .map(|layout| if layout.root.is_empty() { "/" } else { layout.root.as_str() })
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+pub fn summarize_entries(names: &[String]) -> String {
+    let top: BTreeSet<String> = names
+        .iter()
+        .map(|name| {
+            let mut parts = name.splitn(3, '/');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(first), Some(second), Some(_)) => format!("{}/{}/", first, second),
+                (Some(first), Some(_), None) => format!("{}/", first),
+                _ => name.clone(),
+            }
+        })
+        .collect();
+
+    if top.is_empty() {
+        return "архив пуст".to_string();
+    }
+
+    let mut listed: Vec<String> = top.iter().take(MAX_LISTED_ENTRIES).cloned().collect();
+    if top.len() > MAX_LISTED_ENTRIES {
+        listed.push(format!("и ещё {}", top.len() - MAX_LISTED_ENTRIES));
+    }
+    listed.join(", ")
+}
+
+fn pack_root(name: &str) -> Option<&str> {
+    PACK_MARKERS
+        .iter()
+        .filter_map(|marker| {
+            if name.starts_with(marker) {
+                Some(0)
+            } else {
+                name.find(&format!("/{}", marker)).map(|pos| pos + 1)
+            }
+        })
+        .min()
+        .map(|pos| &name[..pos])
+}
+
+fn visit_tar<R, F>(reader: R, visit: &mut F) -> Result<(), String>
+where
+    R: Read,
+    F: FnMut(&str, u64, &mut dyn Read) -> Result<(), String>,
+{
+    let mut archive = tar::Archive::new(reader);
+    let entries = archive
+        .entries()
+        .map_err(|e| format!("Ошибка чтения TAR архива: {}", e))?;
+
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("Ошибка чтения файла из архива: {}", e))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let path = entry
+            .path()
+            .map_err(|e| format!("Ошибка чтения пути в архиве: {}", e))?
+            .into_owned();
+        let safe = path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+        if !safe {
+            return Err("Небезопасный путь в архиве".to_string());
+        }
+
+        let name = path
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        let size = entry.size();
+        visit(&name, size, &mut entry)?;
+    }
+
+    Ok(())
+}
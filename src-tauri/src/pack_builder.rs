@@ -0,0 +1,273 @@
+use crate::APIs::delta_update::{PackIndex, PACK_INDEX_ASSET};
+use crate::APIs::lang_updater::{normalize_version, verify_archive, MAX_ARCHIVE_SIZE, MAX_ENTRY_SIZE};
+use crate::manifest::{ManifestFile, PackComponent, PackManifest, MANIFEST_VERSION, PACK_LOCALE};
+use ed25519_dalek::{Signer, SigningKey};
+use hytaleru_lib::{sha256_hex, write_atomic};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+
+const ARCHIVE_PREFIX: &str = "install/release/package/game/latest/Client/Data/Shared/";
+const DEFAULT_NAME: &str = "Hytale-Russian";
+const PACK_DIRS: [&str; 2] = ["Fonts", "Language/ru-RU"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PackBuildOptions {
+    pub source_dir: String,
+    pub output_dir: Option<String>,
+    pub name: Option<String>,
+    pub signing_key: Option<String>,
+    pub base_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackBuildResult {
+    pub version: String,
+    pub archive: String,
+    pub sha256: String,
+    pub hash_file: String,
+    pub signature_file: Option<String>,
+    pub public_key: Option<String>,
+    pub index_file: Option<String>,
+    pub files: usize,
+}
+
+struct PackFile {
+    logical: String,
+    content: Vec<u8>,
+}
+
+#[tauri::command]
+pub fn build_localization_pack(options: PackBuildOptions) -> Result<PackBuildResult, String> {
+    build_pack(&options)
+}
+
+pub fn build_pack(options: &PackBuildOptions) -> Result<PackBuildResult, String> {
+    let source = PathBuf::from(&options.source_dir);
+    let mut manifest = PackManifest::read_from(&source)?;
+    manifest.parsed_version()?;
+    manifest.supported_builds()?;
+
+    let mut files = collect_pack_files(&source, manifest.is_partial())?;
+    fill_manifest(&mut manifest, &files)?;
+    files.push(PackFile {
+        logical: "manifest.json".to_string(),
+        content: serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| format!("Ошибка сериализации manifest.json: {}", e))?,
+    });
+    files.sort_by(|a, b| a.logical.cmp(&b.logical));
+
+    let version = normalize_version(&manifest.version);
+    let name = options.name.as_deref().unwrap_or(DEFAULT_NAME);
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return Err("Недопустимое имя архива".to_string());
+    }
+
+    let output = match &options.output_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => source.join("dist"),
+    };
+    fs::create_dir_all(&output).map_err(|e| format!("Ошибка создания директории: {}", e))?;
+
+    let archive_name = format!("{}-v{}.zip", name, version);
+    let archive_path = output.join(&archive_name);
+    let bytes = write_deterministic_zip(&files)?;
+    write_atomic(&archive_path, &bytes)?;
+    verify_archive(&archive_path)?;
+
+    let sha256 = sha256_hex(&bytes);
+    let hash_file = output.join(format!("{}.sha256", archive_name));
+    write_atomic(&hash_file, format!("{}  {}\n", sha256, archive_name).as_bytes())?;
+
+    let (signature_file, public_key) = match &options.signing_key {
+        Some(key_path) => {
+            let key = load_signing_key(Path::new(key_path))?;
+            let signature = key.sign(&bytes);
+            let path = output.join(format!("{}.sig", archive_name));
+            write_atomic(&path, format!("{}\n", to_hex(&signature.to_bytes())).as_bytes())?;
+            (
+                Some(path.display().to_string()),
+                Some(to_hex(key.verifying_key().as_bytes())),
+            )
+        }
+        None => (None, None),
+    };
+
+    let index_file = match &options.base_url {
+        Some(base_url) => {
+            let path = output.join(PACK_INDEX_ASSET);
+            write_pack_index(&version, base_url, &path)?;
+            Some(path.display().to_string())
+        }
+        None => None,
+    };
+
+    Ok(PackBuildResult {
+        version,
+        archive: archive_path.display().to_string(),
+        sha256,
+        hash_file: hash_file.display().to_string(),
+        signature_file,
+        public_key,
+        index_file,
+        files: files.len(),
+    })
+}
+
+fn fill_manifest(manifest: &mut PackManifest, files: &[PackFile]) -> Result<(), String> {
+    let mut entries = Vec::with_capacity(files.len());
+    for file in files {
+        let component = PackComponent::for_path(&file.logical)
+            .ok_or(format!("Файл {} не относится ни к одному компоненту", file.logical))?;
+        entries.push(ManifestFile {
+            path: file.logical.clone(),
+            sha256: sha256_hex(&file.content),
+            size: file.content.len() as u64,
+            component: Some(component),
+        });
+    }
+
+    let mut components: Vec<PackComponent> = entries.iter().filter_map(|file| file.component).collect();
+    components.sort();
+    components.dedup();
+
+    manifest.manifest_version = Some(MANIFEST_VERSION);
+    manifest.locale = Some(PACK_LOCALE.to_string());
+    manifest.components = components;
+    manifest.files = entries;
+
+    let bytes = serde_json::to_vec(manifest)
+        .map_err(|e| format!("Ошибка сериализации manifest.json: {}", e))?;
+    PackManifest::from_bytes(&bytes).map(|_| ())
+}
+
+fn collect_pack_files(source: &Path, partial: bool) -> Result<Vec<PackFile>, String> {
+    let mut files = Vec::new();
+
+    for dir in PACK_DIRS {
+        let root = source.join(dir);
+        if !root.is_dir() {
+            if partial {
+                continue;
+            }
+            return Err(format!("Папка {} не найдена в рабочей директории", dir));
+        }
+
+        let before = files.len();
+        collect_dir(&root, dir, &mut files)?;
+        if files.len() == before && !partial {
+            return Err(format!("Папка {} пуста", dir));
+        }
+    }
+
+    if files.is_empty() {
+        return Err("В рабочей директории нет файлов в Fonts и Language/ru-RU".to_string());
+    }
+
+    let total: u64 = files.iter().map(|file| file.content.len() as u64).sum();
+    if total > MAX_ARCHIVE_SIZE {
+        return Err("Пакет слишком большой".to_string());
+    }
+
+    files.sort_by(|a, b| a.logical.cmp(&b.logical));
+    Ok(files)
+}
+
+fn collect_dir(dir: &Path, logical: &str, files: &mut Vec<PackFile>) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|e| format!("Ошибка чтения директории: {}", e))? {
+        let entry = entry.map_err(|e| format!("Ошибка чтения записи: {}", e))?;
+        let file_type = entry
+            .file_type()
+            .map_err(|e| format!("Ошибка чтения записи: {}", e))?;
+        let name = entry
+            .file_name()
+            .into_string()
+            .map_err(|_| format!("Недопустимое имя файла в {}", logical))?;
+        let logical = format!("{}/{}", logical, name);
+
+        if file_type.is_symlink() {
+            return Err(format!("Символические ссылки не поддерживаются: {}", logical));
+        }
+
+        if file_type.is_dir() {
+            collect_dir(&entry.path(), &logical, files)?;
+        } else {
+            let size = entry
+                .metadata()
+                .map_err(|e| format!("Ошибка чтения файла {}: {}", logical, e))?
+                .len();
+            if size > MAX_ENTRY_SIZE {
+                return Err(format!("Файл {} слишком большой", logical));
+            }
+
+            let content = fs::read(entry.path())
+                .map_err(|e| format!("Ошибка чтения файла {}: {}", logical, e))?;
+            files.push(PackFile { logical, content });
+        }
+    }
+    Ok(())
+}
+
+fn write_deterministic_zip(files: &[PackFile]) -> Result<Vec<u8>, String> {
+    let options = FileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .last_modified_time(zip::DateTime::default())
+        .unix_permissions(0o644);
+
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for file in files {
+        let name = if file.logical == "manifest.json" {
+            file.logical.clone()
+        } else {
+            format!("{}{}", ARCHIVE_PREFIX, file.logical)
+        };
+        writer
+            .start_file(name, options)
+            .map_err(|e| format!("Ошибка записи архива: {}", e))?;
+        writer
+            .write_all(&file.content)
+            .map_err(|e| format!("Ошибка записи архива: {}", e))?;
+    }
+
+    let cursor = writer
+        .finish()
+        .map_err(|e| format!("Ошибка записи архива: {}", e))?;
+    Ok(cursor.into_inner())
+}
+
+fn write_pack_index(version: &str, base_url: &str, path: &Path) -> Result<(), String> {
+    let index = PackIndex {
+        version: version.to_string(),
+        base_url: base_url.to_string(),
+    };
+    let content = serde_json::to_vec_pretty(&index)
+        .map_err(|e| format!("Ошибка сериализации индекса пакета: {}", e))?;
+    write_atomic(path, &content)
+}
+
+fn load_signing_key(path: &Path) -> Result<SigningKey, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Ошибка чтения ключа подписи: {}", e))?;
+    let seed: [u8; 32] = from_hex(content.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("Ключ подписи должен содержать 32 байта в шестнадцатеричном виде")?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
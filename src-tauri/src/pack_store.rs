@@ -0,0 +1,391 @@
+use crate::game_build::{check_compatibility, detect_game_build, Compatibility};
+use crate::gamepath::hytale_game_dir;
+use crate::manifest::PackManifest;
+use crate::providers::{active_provider, provider_or_active, validate_provider_id, DEFAULT_PROVIDER};
+use crate::version::Version;
+use hytaleru_lib::{copy_dir_recursive, get_data_dir, replace_dir, resolve_assets_dir, unix_timestamp, write_atomic};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_STORED_VERSIONS: usize = 5;
+const INDEX_FILE: &str = "store.json";
+const BUNDLED_PACK: &str = "bundled";
+const PACK_CONTENTS: [&str; 3] = ["Fonts/withRU", "Language/ru-RU", "manifest.json"];
+const ORIGINAL_FONTS: [&str; 3] = ["Lexend-Bold.json", "Lexend-Bold.png", "Lexend-Bold.ttf"];
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PackStoreIndex {
+    pub pinned: BTreeMap<String, String>,
+    pub versions: Vec<StoredPack>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StoredPack {
+    #[serde(default = "default_provider")]
+    pub provider: String,
+    pub version: String,
+    pub installed_at: u64,
+    #[serde(default)]
+    pub sequence: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StoredPackInfo {
+    pub version: String,
+    pub installed_at: u64,
+    pub active: bool,
+    pub bundled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct PackDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+struct PackCandidate {
+    id: String,
+    dir: PathBuf,
+    version: Version,
+    installed_at: u64,
+    sequence: u64,
+}
+
+#[tauri::command]
+pub fn list_localization_versions(provider: Option<String>) -> Result<Vec<StoredPackInfo>, String> {
+    let provider = provider_id(provider)?;
+    let index = load_index()?;
+    let active = resolve_candidate(&index, &provider)?.map(|candidate| candidate.id);
+
+    let mut candidates = pack_candidates(&index, &provider)?;
+    candidates.sort_by_key(|candidate| std::cmp::Reverse((candidate.sequence, candidate.installed_at)));
+
+    Ok(candidates
+        .into_iter()
+        .map(|candidate| StoredPackInfo {
+            active: active.as_deref() == Some(candidate.id.as_str()),
+            bundled: candidate.id == BUNDLED_PACK,
+            version: candidate.version.to_string(),
+            installed_at: candidate.installed_at,
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn switch_localization_version(version: String, provider: Option<String>) -> Result<(), String> {
+    activate_version(&provider_id(provider)?, &version)
+}
+
+#[tauri::command]
+pub fn reset_localization_selection(provider: Option<String>) -> Result<(), String> {
+    let provider = provider_id(provider)?;
+    let mut index = load_index()?;
+    index.pinned.remove(&provider);
+    save_index(&index)
+}
+
+#[tauri::command]
+pub fn rollback_localization(provider: Option<String>) -> Result<String, String> {
+    let provider = provider_id(provider)?;
+    let index = load_index()?;
+    let current = resolve_candidate(&index, &provider)?.ok_or("Активная версия локализации не найдена")?;
+
+    let previous = pack_candidates(&index, &provider)?
+        .into_iter()
+        .filter(|candidate| candidate.version < current.version)
+        .max_by(|a, b| a.version.cmp(&b.version))
+        .ok_or("Нет предыдущей версии для отката")?;
+
+    activate_version(&provider, &previous.id)?;
+    Ok(previous.version.to_string())
+}
+
+#[tauri::command]
+pub fn compare_localization_versions(from: String, to: String, provider: Option<String>) -> Result<PackDiff, String> {
+    let provider = provider_id(provider)?;
+    let index = load_index()?;
+    let from_dir = candidate_dir(&index, &provider, &from)?;
+    let to_dir = candidate_dir(&index, &provider, &to)?;
+
+    let from_files = list_files(&from_dir)?;
+    let to_files = list_files(&to_dir)?;
+    let mut diff = PackDiff::default();
+
+    for file in &to_files {
+        if !from_files.contains(file) {
+            diff.added.push(file.clone());
+            continue;
+        }
+
+        let left = fs::read(from_dir.join(file)).map_err(|e| format!("Ошибка чтения файла: {}", e))?;
+        let right = fs::read(to_dir.join(file)).map_err(|e| format!("Ошибка чтения файла: {}", e))?;
+        if left != right {
+            diff.changed.push(file.clone());
+        }
+    }
+
+    diff.removed = from_files.difference(&to_files).cloned().collect();
+    Ok(diff)
+}
+
+#[tauri::command]
+pub fn select_compatible_version(provider: Option<String>) -> Result<String, String> {
+    let provider = provider_id(provider)?;
+    let game = hytale_game_dir()?;
+    let game_build = detect_game_build(&game).ok_or("Не удалось определить сборку игры")?;
+    let index = load_index()?;
+
+    let mut best: Option<PackCandidate> = None;
+    for candidate in pack_candidates(&index, &provider)? {
+        let manifest = match PackManifest::read_from(&candidate.dir) {
+            Ok(manifest) => manifest,
+            Err(_) => continue,
+        };
+        if check_compatibility(&manifest, Some(&game_build))? != Compatibility::Compatible {
+            continue;
+        }
+        if best.as_ref().is_none_or(|current| candidate.version > current.version) {
+            best = Some(candidate);
+        }
+    }
+
+    let selected = best.ok_or(format!(
+        "В хранилище нет локализации для сборки игры {}",
+        game_build
+    ))?;
+    activate_version(&provider, &selected.id)?;
+    Ok(selected.version.to_string())
+}
+
+pub fn resolve_pack_dir() -> Result<PathBuf, String> {
+    resolve_pack_dir_for(&active_provider()?.id)
+}
+
+pub fn resolve_pack_dir_for(provider: &str) -> Result<PathBuf, String> {
+    let index = load_index()?;
+    resolve_candidate(&index, provider)?
+        .map(|candidate| candidate.dir)
+        .ok_or("Пакет локализации не найден".to_string())
+}
+
+pub fn original_fonts_dir() -> Option<PathBuf> {
+    let bundled = resolve_assets_dir().ok().map(|dir| dir.join("Fonts").join("original"));
+    let user = get_data_dir().ok().map(|dir| dir.join("Fonts").join("original"));
+
+    [bundled, user]
+        .into_iter()
+        .flatten()
+        .find(|dir| ORIGINAL_FONTS.iter().all(|name| dir.join(name).exists()))
+}
+
+pub fn user_original_fonts_dir() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join("Fonts").join("original"))
+}
+
+pub fn staging_dir(name: &str) -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join("staging").join(name))
+}
+
+pub fn store_version(source_dir: &Path, provider: &str, version: &str) -> Result<(), String> {
+    let target = version_dir(provider, version)?;
+    let temp = target.with_file_name(format!(".{}_tmp", version));
+    if temp.exists() {
+        fs::remove_dir_all(&temp).map_err(|e| format!("Ошибка очистки директории: {}", e))?;
+    }
+
+    for relative in ["Fonts/withRU", "Language/ru-RU"] {
+        if source_dir.join(relative).is_dir() {
+            copy_dir_recursive(&source_dir.join(relative), &temp.join(relative))?;
+        }
+    }
+    fs::copy(source_dir.join("manifest.json"), temp.join("manifest.json"))
+        .map_err(|e| format!("Ошибка копирования файла: {}", e))?;
+    replace_dir(&temp, &target)?;
+
+    let mut index = load_index()?;
+    let sequence = index.versions.iter().map(|pack| pack.sequence).max().unwrap_or(0) + 1;
+    index
+        .versions
+        .retain(|pack| pack.provider != provider || pack.version != version);
+    index.versions.push(StoredPack {
+        provider: provider.to_string(),
+        version: version.to_string(),
+        installed_at: unix_timestamp()?,
+        sequence,
+    });
+    index.pinned.remove(provider);
+    prune(&mut index, provider)?;
+    save_index(&index)
+}
+
+fn activate_version(provider: &str, version: &str) -> Result<(), String> {
+    let mut index = load_index()?;
+    let id = pack_candidates(&index, provider)?
+        .into_iter()
+        .find(|candidate| candidate.id == version || candidate.version.to_string() == version)
+        .map(|candidate| candidate.id)
+        .ok_or("Версия не найдена в хранилище")?;
+
+    index.pinned.insert(provider.to_string(), id);
+    save_index(&index)
+}
+
+fn resolve_candidate(index: &PackStoreIndex, provider: &str) -> Result<Option<PackCandidate>, String> {
+    let mut candidates = pack_candidates(index, provider)?;
+
+    if let Some(pinned) = index.pinned.get(provider) {
+        if let Some(position) = candidates.iter().position(|candidate| &candidate.id == pinned) {
+            return Ok(Some(candidates.swap_remove(position)));
+        }
+    }
+
+    Ok(candidates.into_iter().max_by(|a, b| {
+        a.version
+            .cmp(&b.version)
+            .then_with(|| (b.id == BUNDLED_PACK).cmp(&(a.id == BUNDLED_PACK)))
+    }))
+}
+
+fn pack_candidates(index: &PackStoreIndex, provider: &str) -> Result<Vec<PackCandidate>, String> {
+    let mut candidates = Vec::new();
+
+    let bundled = resolve_assets_dir().ok().filter(|_| provider == DEFAULT_PROVIDER);
+    if let Some(bundled) = bundled {
+        if let Some(version) = valid_pack_version(&bundled) {
+            candidates.push(PackCandidate {
+                id: BUNDLED_PACK.to_string(),
+                dir: bundled,
+                version,
+                installed_at: 0,
+                sequence: 0,
+            });
+        }
+    }
+
+    for pack in index.versions.iter().filter(|pack| pack.provider == provider) {
+        let dir = version_dir(provider, &pack.version)?;
+        if let Some(version) = valid_pack_version(&dir) {
+            candidates.push(PackCandidate {
+                id: pack.version.clone(),
+                dir,
+                version,
+                installed_at: pack.installed_at,
+                sequence: pack.sequence,
+            });
+        }
+    }
+
+    Ok(candidates)
+}
+
+fn valid_pack_version(dir: &Path) -> Option<Version> {
+    let has_content = dir.join("Fonts").join("withRU").is_dir() || dir.join("Language").join("ru-RU").is_dir();
+    if !has_content {
+        return None;
+    }
+
+    PackManifest::read_from(dir).ok()?.parsed_version().ok()
+}
+
+fn candidate_dir(index: &PackStoreIndex, provider: &str, version: &str) -> Result<PathBuf, String> {
+    pack_candidates(index, provider)?
+        .into_iter()
+        .find(|candidate| candidate.id == version || candidate.version.to_string() == version)
+        .map(|candidate| candidate.dir)
+        .ok_or("Версия не найдена в хранилище".to_string())
+}
+
+fn prune(index: &mut PackStoreIndex, provider: &str) -> Result<(), String> {
+    index.versions.sort_by_key(|pack| (pack.sequence, pack.installed_at));
+    let pinned = index.pinned.get(provider).cloned();
+
+    while index.versions.iter().filter(|pack| pack.provider == provider).count() > MAX_STORED_VERSIONS {
+        let position = index
+            .versions
+            .iter()
+            .position(|pack| pack.provider == provider && pinned.as_deref() != Some(pack.version.as_str()))
+            .ok_or("Ошибка очистки хранилища версий")?;
+        let removed = index.versions.remove(position);
+        let dir = version_dir(provider, &removed.version)?;
+        if dir.exists() {
+            fs::remove_dir_all(&dir).map_err(|e| format!("Ошибка удаления версии: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn packs_dir() -> Result<PathBuf, String> {
+    let dir = get_data_dir()?.join("packs");
+    fs::create_dir_all(&dir).map_err(|e| format!("Ошибка создания директории: {}", e))?;
+    Ok(dir)
+}
+
+fn version_dir(provider: &str, version: &str) -> Result<PathBuf, String> {
+    validate_provider_id(provider)?;
+    let valid = !version.is_empty()
+        && !version.starts_with('.')
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'));
+    if !valid {
+        return Err("Недопустимая версия локализации".to_string());
+    }
+
+    Ok(packs_dir()?.join("versions").join(provider).join(version))
+}
+
+fn provider_id(provider: Option<String>) -> Result<String, String> {
+    provider_or_active(provider).map(|provider| provider.id)
+}
+
+fn default_provider() -> String {
+    DEFAULT_PROVIDER.to_string()
+}
+
+fn load_index() -> Result<PackStoreIndex, String> {
+    let path = packs_dir()?.join(INDEX_FILE);
+    if !path.exists() {
+        return Ok(PackStoreIndex::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Ошибка чтения хранилища версий: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Ошибка парсинга хранилища версий: {}", e))
+}
+
+fn save_index(index: &PackStoreIndex) -> Result<(), String> {
+    let content = serde_json::to_vec_pretty(index)
+        .map_err(|e| format!("Ошибка сериализации хранилища версий: {}", e))?;
+    write_atomic(&packs_dir()?.join(INDEX_FILE), &content)
+}
+
+fn list_files(root: &Path) -> Result<BTreeSet<String>, String> {
+    let mut files = BTreeSet::new();
+    for relative in PACK_CONTENTS {
+        let path = root.join(relative);
+        if path.is_dir() {
+            collect_files(root, &path, &mut files)?;
+        } else if path.exists() {
+            files.insert(relative.to_string());
+        }
+    }
+    Ok(files)
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut BTreeSet<String>) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|e| format!("Ошибка чтения директории: {}", e))? {
+        let entry = entry.map_err(|e| format!("Ошибка чтения записи: {}", e))?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.insert(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    Ok(())
+}
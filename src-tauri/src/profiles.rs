@@ -0,0 +1,134 @@
+use crate::gamepath::{get_hytale_root_from_path, hytale_game_dir};
+use crate::lang::validate::KeyProblem;
+use crate::localization::{install_ru, InstallReport};
+use crate::providers::provider_for_game;
+use crate::settings::{load_settings, save_settings};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const DEFAULT_PROFILE: &str = "default";
+const GAME_SUBDIR: &str = "install/release/package/game/latest";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct GameProfile {
+    pub name: String,
+    pub root: String,
+    pub provider: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ApplyStatus {
+    Applied,
+    Skipped,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProfileApplyResult {
+    pub profile: String,
+    pub game_path: String,
+    pub status: ApplyStatus,
+    pub error: Option<String>,
+    pub warnings: Vec<String>,
+    pub problems: Vec<KeyProblem>,
+}
+
+#[tauri::command]
+pub fn list_game_profiles() -> Result<Vec<GameProfile>, String> {
+    all_profiles()
+}
+
+#[tauri::command]
+pub fn add_game_profile(name: String, path: String) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name == DEFAULT_PROFILE {
+        return Err("Недопустимое имя профиля".to_string());
+    }
+
+    let root = get_hytale_root_from_path(&PathBuf::from(&path));
+    if !root.join(GAME_SUBDIR).join("Client/HytaleClient.exe").exists() {
+        return Err("Файл HytaleClient.exe не найден".to_string());
+    }
+
+    let mut settings = load_settings()?;
+    settings.profiles.retain(|profile| profile.name != name);
+    settings.profiles.push(GameProfile {
+        name,
+        root: root.display().to_string(),
+        provider: None,
+    });
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn remove_game_profile(name: String) -> Result<(), String> {
+    let mut settings = load_settings()?;
+    settings.profiles.retain(|profile| profile.name != name);
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn apply_to_installed_profiles() -> Result<Vec<ProfileApplyResult>, String> {
+    apply_to_profiles(None)
+}
+
+pub fn all_profiles() -> Result<Vec<GameProfile>, String> {
+    let mut profiles = load_settings()?.profiles;
+
+    if let Ok(game) = hytale_game_dir() {
+        let root = get_hytale_root_from_path(&game).display().to_string();
+        if !profiles.iter().any(|profile| profile.root == root) {
+            profiles.insert(
+                0,
+                GameProfile {
+                    name: DEFAULT_PROFILE.to_string(),
+                    root,
+                    provider: None,
+                },
+            );
+        }
+    }
+
+    Ok(profiles)
+}
+
+pub fn profile_game_dir(profile: &GameProfile) -> PathBuf {
+    PathBuf::from(&profile.root).join(GAME_SUBDIR)
+}
+
+pub fn apply_to_profiles(provider: Option<&str>) -> Result<Vec<ProfileApplyResult>, String> {
+    let results = all_profiles()?
+        .iter()
+        .filter(|profile| {
+            provider.is_none_or(|id| provider_for_game(&profile_game_dir(profile)).is_ok_and(|p| p.id == id))
+        })
+        .map(|profile| {
+            let game = profile_game_dir(profile);
+            let installed = game
+                .join("Client/Data/Shared/Language/ru-RU/client.lang")
+                .exists();
+
+            let (status, error, report) = if !installed {
+                (ApplyStatus::Skipped, None, InstallReport::default())
+            } else {
+                match install_ru(&game) {
+                    Ok(report) => (ApplyStatus::Applied, None, report),
+                    Err(e) => (ApplyStatus::Failed, Some(e), InstallReport::default()),
+                }
+            };
+
+            ProfileApplyResult {
+                profile: profile.name.clone(),
+                game_path: game.display().to_string(),
+                status,
+                error,
+                warnings: report.warnings,
+                problems: report.problems,
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
@@ -0,0 +1,196 @@
+use crate::manifest::PackManifest;
+use crate::pack_store::resolve_pack_dir_for;
+use crate::profiles::all_profiles;
+use crate::settings::{load_settings, save_settings};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const DEFAULT_PROVIDER: &str = "zentq";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Provider {
+    pub id: String,
+    pub name: String,
+    pub repository: String,
+    pub asset_pattern: String,
+    pub authors: Vec<String>,
+    pub homepage: Option<String>,
+    pub builtin: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProviderInfo {
+    #[serde(flatten)]
+    pub provider: Provider,
+    pub installed_version: Option<String>,
+    pub active: bool,
+}
+
+#[tauri::command]
+pub fn list_providers() -> Result<Vec<ProviderInfo>, String> {
+    let active = active_provider()?.id;
+
+    all_providers()?
+        .into_iter()
+        .map(|mut provider| {
+            let manifest = resolve_pack_dir_for(&provider.id)
+                .ok()
+                .and_then(|dir| PackManifest::read_from(&dir).ok());
+
+            if let Some(manifest) = &manifest {
+                let authors: Vec<String> = manifest
+                    .authors
+                    .iter()
+                    .map(|author| author.name.clone())
+                    .filter(|name| !name.is_empty())
+                    .collect();
+                if !authors.is_empty() {
+                    provider.authors = authors;
+                }
+                if manifest.website.is_some() {
+                    provider.homepage = manifest.website.clone();
+                }
+            }
+
+            Ok(ProviderInfo {
+                active: provider.id == active,
+                installed_version: manifest.map(|m| m.version),
+                provider,
+            })
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn add_provider(provider: Provider) -> Result<(), String> {
+    validate_provider_id(&provider.id)?;
+
+    let repository_valid = provider
+        .repository
+        .split_once('/')
+        .is_some_and(|(owner, repo)| {
+            !owner.is_empty() && !repo.is_empty() && !repo.contains('/')
+        });
+    if !repository_valid {
+        return Err("Репозиторий должен быть указан в формате owner/repo".to_string());
+    }
+
+    if builtin_providers().iter().any(|p| p.id == provider.id) {
+        return Err("Встроенный источник нельзя переопределить".to_string());
+    }
+
+    let mut settings = load_settings()?;
+    settings.providers.retain(|p| p.id != provider.id);
+    settings.providers.push(Provider {
+        builtin: false,
+        ..provider
+    });
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn remove_provider(id: String) -> Result<(), String> {
+    if builtin_providers().iter().any(|p| p.id == id) {
+        return Err("Встроенный источник нельзя удалить".to_string());
+    }
+
+    let mut settings = load_settings()?;
+    settings.providers.retain(|p| p.id != id);
+    if settings.active_provider.as_deref() == Some(id.as_str()) {
+        settings.active_provider = None;
+    }
+    for profile in &mut settings.profiles {
+        if profile.provider.as_deref() == Some(id.as_str()) {
+            profile.provider = None;
+        }
+    }
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn set_active_provider(id: String) -> Result<(), String> {
+    find_provider(&id)?;
+    let mut settings = load_settings()?;
+    settings.active_provider = Some(id);
+    save_settings(&settings)
+}
+
+#[tauri::command]
+pub fn set_profile_provider(profile: String, provider: String) -> Result<(), String> {
+    find_provider(&provider)?;
+
+    let mut settings = load_settings()?;
+    let mut profiles = all_profiles()?;
+    let entry = profiles
+        .iter_mut()
+        .find(|p| p.name == profile)
+        .ok_or("Профиль не найден")?;
+    entry.provider = Some(provider);
+
+    let entry = entry.clone();
+    settings.profiles.retain(|p| p.name != entry.name);
+    settings.profiles.push(entry);
+    save_settings(&settings)
+}
+
+pub fn builtin_providers() -> Vec<Provider> {
+    vec![Provider {
+        id: DEFAULT_PROVIDER.to_string(),
+        name: "Hytale Russian Translation".to_string(),
+        repository: "zzentq/HytaleRussianTranslation".to_string(),
+        asset_pattern: "Hytale-Russian".to_string(),
+        authors: vec!["ZENT".to_string()],
+        homepage: Some("https://github.com/zzentq/HytaleRussianTranslation".to_string()),
+        builtin: true,
+    }]
+}
+
+pub fn all_providers() -> Result<Vec<Provider>, String> {
+    let mut providers = builtin_providers();
+    providers.extend(load_settings()?.providers);
+    Ok(providers)
+}
+
+pub fn find_provider(id: &str) -> Result<Provider, String> {
+    all_providers()?
+        .into_iter()
+        .find(|provider| provider.id == id)
+        .ok_or(format!("Источник локализации {} не найден", id))
+}
+
+pub fn provider_or_active(id: Option<String>) -> Result<Provider, String> {
+    match id {
+        Some(id) => find_provider(&id),
+        None => active_provider(),
+    }
+}
+
+pub fn active_provider() -> Result<Provider, String> {
+    let id = load_settings()?
+        .active_provider
+        .unwrap_or_else(|| DEFAULT_PROVIDER.to_string());
+    find_provider(&id).or_else(|_| find_provider(DEFAULT_PROVIDER))
+}
+
+pub fn provider_for_game(game: &Path) -> Result<Provider, String> {
+    let profile = all_profiles()?
+        .into_iter()
+        .find(|profile| crate::profiles::profile_game_dir(profile) == game);
+
+    match profile.and_then(|p| p.provider) {
+        Some(id) => find_provider(&id),
+        None => active_provider(),
+    }
+}
+
+pub fn validate_provider_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'));
+    if !valid {
+        return Err("Недопустимый идентификатор источника".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::lang::lint::LintConfig;
+use crate::lang::typography::TypographyConfig;
+use crate::layers::TranslationLayer;
+use crate::profiles::GameProfile;
+use crate::providers::Provider;
+use hytaleru_lib::{get_config_dir, write_atomic};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AppSettings {
+    pub github_token: Option<String>,
+    pub proxy_url: Option<String>,
+    pub extra_root_certificates: Vec<String>,
+    pub connect_timeout_secs: Option<u64>,
+    pub request_timeout_secs: Option<u64>,
+    pub offline_mode: bool,
+    pub compatibility_policy: CompatibilityPolicy,
+    pub validation_policy: CompatibilityPolicy,
+    pub auto_apply_updates: bool,
+    pub fill_missing_from_english: bool,
+    pub profiles: Vec<GameProfile>,
+    pub providers: Vec<Provider>,
+    pub active_provider: Option<String>,
+    pub enabled_packs: Vec<String>,
+    pub translation_layers: Vec<TranslationLayer>,
+    pub lint: LintConfig,
+    pub typography: TypographyConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CompatibilityPolicy {
+    #[default]
+    Warn,
+    Block,
+}
+
+#[tauri::command]
+pub fn get_app_settings() -> Result<AppSettings, String> {
+    load_settings()
+}
+
+#[tauri::command]
+pub fn save_app_settings(settings: AppSettings) -> Result<(), String> {
+    save_settings(&settings)
+}
+
+pub fn load_settings() -> Result<AppSettings, String> {
+    let path = get_config_dir()?.join(SETTINGS_FILE);
+    if !path.exists() {
+        return Ok(AppSettings::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Ошибка чтения настроек: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Ошибка парсинга настроек: {}", e))
+}
+
+pub fn save_settings(settings: &AppSettings) -> Result<(), String> {
+    let content = serde_json::to_vec_pretty(settings)
+        .map_err(|e| format!("Ошибка сериализации настроек: {}", e))?;
+    write_atomic(&get_config_dir()?.join(SETTINGS_FILE), &content)
+}
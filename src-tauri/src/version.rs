@@ -0,0 +1,514 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Vec<Identifier>,
+    pub build: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Identifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct VersionReq {
+    alternatives: Vec<Vec<Comparator>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Caret,
+    Tilde,
+}
+
+#[derive(Debug, Clone)]
+struct Comparator {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Vec<Identifier>,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+            pre: Vec::new(),
+            build: Vec::new(),
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let trimmed = value.trim();
+        let text = trimmed.strip_prefix(['v', 'V']).unwrap_or(trimmed);
+        let invalid = || format!("Некорректная версия: {}", value.trim());
+
+        let (text, build) = match text.split_once('+') {
+            Some((core, build)) => (core, Some(build)),
+            None => (text, None),
+        };
+        let (core, pre) = match text.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (text, None),
+        };
+
+        let numbers: Vec<&str> = core.split('.').collect();
+        if numbers.len() != 3 {
+            return Err(invalid());
+        }
+
+        let major = parse_numeric(numbers[0]).ok_or_else(invalid)?;
+        let minor = parse_numeric(numbers[1]).ok_or_else(invalid)?;
+        let patch = parse_numeric(numbers[2]).ok_or_else(invalid)?;
+
+        let pre = match pre {
+            Some(pre) => parse_pre(pre).ok_or_else(invalid)?,
+            None => Vec::new(),
+        };
+
+        let build = match build {
+            Some(build) => build
+                .split('.')
+                .map(|part| is_identifier(part).then(|| part.to_string()))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)?,
+            None => Vec::new(),
+        };
+
+        Ok(Version {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+
+    pub fn parse_game_build(value: &str) -> Result<Self, String> {
+        let trimmed = value.trim();
+        let (core, suffix) = match trimmed.split_once(['-', '+']) {
+            Some((core, suffix)) => (core, Some(suffix)),
+            None => (trimmed, None),
+        };
+
+        let mut numbers: Vec<String> = core
+            .split('.')
+            .map(|part| {
+                let stripped = part.trim_start_matches('0');
+                if stripped.is_empty() && !part.is_empty() {
+                    "0".to_string()
+                } else {
+                    stripped.to_string()
+                }
+            })
+            .collect();
+        while numbers.len() < 3 {
+            numbers.push("0".to_string());
+        }
+
+        let normalized = match suffix {
+            Some(suffix) => format!("{}+{}", numbers.join("."), suffix.replace(['-', '+'], ".")),
+            None => numbers.join("."),
+        };
+
+        Version::parse(&normalized).map_err(|_| format!("Некорректная сборка игры: {}", trimmed))
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| compare_pre(&self.pre, &other.pre))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", join_identifiers(&self.pre))?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Identifier::Numeric(n) => write!(f, "{}", n),
+            Identifier::AlphaNumeric(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl VersionReq {
+    pub fn any() -> Self {
+        VersionReq {
+            alternatives: vec![Vec::new()],
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut alternatives = Vec::new();
+
+        for alternative in value.split("||") {
+            let mut comparators = Vec::new();
+            let mut tokens = alternative
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|t| !t.is_empty())
+                .peekable();
+
+            while let Some(token) = tokens.next() {
+                let token = if matches!(token, ">" | ">=" | "<" | "<=" | "=" | "^" | "~") {
+                    match tokens.next() {
+                        Some(next) => format!("{}{}", token, next),
+                        None => return Err(format!("Некорректное ограничение версии: {}", value.trim())),
+                    }
+                } else {
+                    token.to_string()
+                };
+
+                if let Some(comparator) = parse_comparator(&token)
+                    .map_err(|_| format!("Некорректное ограничение версии: {}", value.trim()))?
+                {
+                    comparators.push(comparator);
+                }
+            }
+
+            alternatives.push(comparators);
+        }
+
+        Ok(VersionReq { alternatives })
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        self.alternatives.iter().any(|comparators| {
+            comparators.iter().all(|c| c.matches(version))
+                && (!version.is_prerelease()
+                    || comparators.iter().any(|c| c.allows_prerelease_of(version)))
+        })
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alternatives: Vec<String> = self
+            .alternatives
+            .iter()
+            .map(|comparators| {
+                if comparators.is_empty() {
+                    "*".to_string()
+                } else {
+                    comparators
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }
+            })
+            .collect();
+        write!(f, "{}", alternatives.join(" || "))
+    }
+}
+
+impl Comparator {
+    fn lower(&self) -> Version {
+        Version {
+            major: self.major,
+            minor: self.minor.unwrap_or(0),
+            patch: self.patch.unwrap_or(0),
+            pre: self.pre.clone(),
+            build: Vec::new(),
+        }
+    }
+
+    fn matches(&self, version: &Version) -> bool {
+        let lower = self.lower();
+
+        match self.op {
+            Op::Exact => match (self.minor, self.patch) {
+                (Some(_), Some(_)) => *version == lower,
+                (Some(minor), None) => version.major == self.major && version.minor == minor,
+                _ => version.major == self.major,
+            },
+            Op::Greater => match (self.minor, self.patch) {
+                (Some(_), Some(_)) => *version > lower,
+                (Some(minor), None) => {
+                    *version >= Version::new(self.major, minor + 1, 0)
+                }
+                _ => *version >= Version::new(self.major + 1, 0, 0),
+            },
+            Op::GreaterEq => *version >= lower,
+            Op::Less => *version < lower,
+            Op::LessEq => match (self.minor, self.patch) {
+                (Some(_), Some(_)) => *version <= lower,
+                (Some(minor), None) => *version < Version::new(self.major, minor + 1, 0),
+                _ => *version < Version::new(self.major + 1, 0, 0),
+            },
+            Op::Tilde => {
+                let upper = match self.minor {
+                    Some(minor) => Version::new(self.major, minor + 1, 0),
+                    None => Version::new(self.major + 1, 0, 0),
+                };
+                *version >= lower && *version < upper
+            }
+            Op::Caret => {
+                let upper = match (self.major, self.minor, self.patch) {
+                    (0, Some(0), Some(patch)) => Version::new(0, 0, patch + 1),
+                    (0, Some(minor), _) => Version::new(0, minor + 1, 0),
+                    (major, _, _) => Version::new(major + 1, 0, 0),
+                };
+                *version >= lower && *version < upper
+            }
+        }
+    }
+
+    fn allows_prerelease_of(&self, version: &Version) -> bool {
+        !self.pre.is_empty()
+            && self.major == version.major
+            && self.minor == Some(version.minor)
+            && self.patch == Some(version.patch)
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Caret => "^",
+            Op::Tilde => "~",
+        };
+        write!(f, "{}{}", op, self.major)?;
+        if let Some(minor) = self.minor {
+            write!(f, ".{}", minor)?;
+        }
+        if let Some(patch) = self.patch {
+            write!(f, ".{}", patch)?;
+        }
+        if !self.pre.is_empty() {
+            write!(f, "-{}", join_identifiers(&self.pre))?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_comparator(token: &str) -> Result<Option<Comparator>, ()> {
+    let (op, rest) = [
+        (">=", Op::GreaterEq),
+        ("<=", Op::LessEq),
+        (">", Op::Greater),
+        ("<", Op::Less),
+        ("=", Op::Exact),
+        ("^", Op::Caret),
+        ("~", Op::Tilde),
+    ]
+    .iter()
+    .find_map(|(prefix, op)| token.strip_prefix(prefix).map(|rest| (*op, rest)))
+    .unwrap_or((Op::Exact, token));
+
+    let rest = rest.strip_prefix(['v', 'V']).unwrap_or(rest);
+    if matches!(rest, "*" | "x" | "X") {
+        return if op == Op::Exact { Ok(None) } else { Err(()) };
+    }
+
+    let rest = rest.split_once('+').map(|(core, _)| core).unwrap_or(rest);
+    let (core, pre) = match rest.split_once('-') {
+        Some((core, pre)) => (core, parse_pre(pre).ok_or(())?),
+        None => (rest, Vec::new()),
+    };
+
+    let mut parts = core.split('.');
+    let major = parse_numeric(parts.next().ok_or(())?).ok_or(())?;
+    let minor = parse_part(parts.next())?;
+    let patch = parse_part(parts.next())?;
+    if parts.next().is_some() || (minor.is_none() && patch.is_some()) {
+        return Err(());
+    }
+    if !pre.is_empty() && patch.is_none() {
+        return Err(());
+    }
+
+    Ok(Some(Comparator {
+        op,
+        major,
+        minor,
+        patch,
+        pre,
+    }))
+}
+
+fn parse_part(part: Option<&str>) -> Result<Option<u64>, ()> {
+    match part {
+        None | Some("*") | Some("x") | Some("X") => Ok(None),
+        Some(value) => parse_numeric(value).map(Some).ok_or(()),
+    }
+}
+
+fn parse_numeric(value: &str) -> Option<u64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if value.len() > 1 && value.starts_with('0') {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn parse_pre(value: &str) -> Option<Vec<Identifier>> {
+    value
+        .split('.')
+        .map(|part| {
+            if !is_identifier(part) {
+                None
+            } else if part.bytes().all(|b| b.is_ascii_digit()) {
+                parse_numeric(part).map(Identifier::Numeric)
+            } else {
+                Some(Identifier::AlphaNumeric(part.to_string()))
+            }
+        })
+        .collect()
+}
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+fn compare_pre(left: &[Identifier], right: &[Identifier]) -> Ordering {
+    match (left.is_empty(), right.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
+    }
+
+    for (l, r) in left.iter().zip(right.iter()) {
+        let ordering = match (l, r) {
+            (Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+            (Identifier::Numeric(_), Identifier::AlphaNumeric(_)) => Ordering::Less,
+            (Identifier::AlphaNumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+            (Identifier::AlphaNumeric(a), Identifier::AlphaNumeric(b)) => a.cmp(b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    left.len().cmp(&right.len())
+}
+
+fn join_identifiers(identifiers: &[Identifier]) -> String {
+    identifiers
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(value: &str) -> Version {
+        Version::parse(value).unwrap()
+    }
+
+    fn req(value: &str) -> VersionReq {
+        VersionReq::parse(value).unwrap()
+    }
+
+    #[test]
+    fn precedence_follows_semver() {
+        let order = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.10.0",
+        ];
+        for pair in order.windows(2) {
+            assert!(version(pair[0]) < version(pair[1]), "{:?}", pair);
+        }
+        assert_eq!(version("1.0.0+abc"), version("v1.0.0+def"));
+        assert_eq!(version("1.2.3-rc.1+b.5").to_string(), "1.2.3-rc.1+b.5");
+    }
+
+    #[test]
+    fn parse_rejects_invalid_versions() {
+        for value in ["1.2", "01.2.3", "1.2.3-", "1.2.3-01", "abc"] {
+            assert!(Version::parse(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn requirements_match_ranges() {
+        assert!(req("*").matches(&version("9.9.9")));
+        assert!(!req("*").matches(&version("9.9.9-rc.1")));
+        assert!(req(">=1.2.0, <2").matches(&version("1.5.0")));
+        assert!(!req(">=1.2.0, <2").matches(&version("2.0.0")));
+        assert!(req("^1.2").matches(&version("1.9.0")));
+        assert!(!req("^0.2.3").matches(&version("0.3.0")));
+        assert!(req("~1.2.3").matches(&version("1.2.9")));
+        assert!(!req("~1.2.3").matches(&version("1.3.0")));
+        assert!(req("1.2.x").matches(&version("1.2.7")));
+        assert!(req(">= 1.0.0-rc.1").matches(&version("1.0.0-rc.2")));
+        assert!(req("1.x || >=3").matches(&version("3.1.0")));
+        assert!(!req("1.x || >=3").matches(&version("2.0.0")));
+        assert!(VersionReq::parse(">=abc").is_err());
+        assert_eq!(req(">=1.2, <2").to_string(), ">=1.2, <2");
+    }
+
+    #[test]
+    fn game_builds_parse_as_versions() {
+        let build = Version::parse_game_build("2026.01.13-50e69c385").unwrap();
+        assert_eq!(build.to_string(), "2026.1.13+50e69c385");
+        assert!(req(">=2026.1.10").matches(&build));
+        assert!(!req("<2026.1.13").matches(&build));
+    }
+}
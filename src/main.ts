@@ -1,12 +1,14 @@
 import { invoke } from "@tauri-apps/api/core";
 import { getVersion } from "@tauri-apps/api/app";
 import { showToast } from "./ui/toast";
-import { checkLocalizationUpdates } from "./services/localization";
+import { checkLocalizationUpdates, installPackFromFile, selectPackFile } from "./services/localization";
 import { checkForUpdates } from "./services/update";
 import { cutToHytaleRoot, openGamePath, selectGamePath, validatePath } from "./services/gamePath";
+import { InstallReport } from "./types";
 
 const actionBtn = document.getElementById("action-btn")                 as HTMLButtonElement;
 const folderBtn = document.getElementById("folder-btn")                 as HTMLButtonElement;
+const fileBtn = document.getElementById("file-btn")                     as HTMLButtonElement;
 const gamePathIconBtn = document.getElementById("gamepath-icon-btn")    as HTMLButtonElement;
 const gamePathTooltip = document.getElementById("gamepath-tooltip")     as HTMLSpanElement;
 const versionDisplay = document.getElementById("version-display")       as HTMLDivElement;
@@ -131,6 +133,23 @@ folderBtn.addEventListener("click", async () => {
 	if (!selected) return;
 	await validateAndSetPath(selected);
 });
+fileBtn.addEventListener("click", async () => {
+  const selected = await selectPackFile();
+  if (!selected) return;
+  fileBtn.disabled = true;
+
+  try {
+    const summary = await installPackFromFile(selected);
+    if (!summary) return;
+    langVersion = summary.version;
+    updateVersionDisplay();
+    showToast("Локализация установлена из файла", "status-success");
+  } catch (err) {
+    showToast(`Ошибка: ${err}`, "status-error");
+  } finally {
+    fileBtn.disabled = false;
+  }
+});
 actionBtn.addEventListener("click", async () => {
   if (!validPath) return;
   actionBtn.disabled = true;
@@ -141,8 +160,13 @@ actionBtn.addEventListener("click", async () => {
       await invoke("restore_original_cmd");
       showToast("Удалён русский язык", "status-neutral");
     } else {
-      await invoke("install_ru_cmd");
-      showToast("Русский язык установлен", "status-success");
+      const report = await invoke<InstallReport>("install_ru_cmd");
+      const issues = report.warnings.length + report.problems.length;
+      if (issues > 0) {
+        showToast(`Русский язык установлен, предупреждений: ${issues}`, "status-neutral");
+      } else {
+        showToast("Русский язык установлен", "status-success");
+      }
     }
 
     ruInstalled = await invoke<boolean>("check_ru_installed", { path: validPath });
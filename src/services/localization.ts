@@ -1,5 +1,20 @@
 import { invoke } from "@tauri-apps/api/core";
-import { LocalizationUpdateInfo } from "../types";
+import { ask, open } from "@tauri-apps/plugin-dialog";
+import {
+  CoverageReport,
+  ExchangeFormat,
+  GlossaryIssue,
+  ImportReport,
+  KeyProblem,
+  LintReport,
+  LocalPackSummary,
+  LocalizationUpdateInfo,
+  LocalizationUpdateOutcome,
+  PackBuildOptions,
+  PackBuildResult,
+  StaleReport,
+  TypographyChange,
+} from "../types";
 
 export type LocalizationUpdateResult =
   | { ok: true; langVersion: string | null; updateAvailable: boolean; updated: boolean }
@@ -15,7 +30,8 @@ export async function checkLocalizationUpdates(): Promise<LocalizationUpdateResu
     }
 
 		try {
-			const updated = await invoke<boolean>("auto_update_localization");
+			const outcome = await invoke<LocalizationUpdateOutcome>("auto_update_localization");
+			const updated = outcome.updated;
 			const langVersion = updated ? info.latest_version : currentVersion;
 			return { ok: true, langVersion, updateAvailable: true, updated };
 		} catch (error) {
@@ -27,4 +43,103 @@ export async function checkLocalizationUpdates(): Promise<LocalizationUpdateResu
     console.error("Ошибка проверки локализации:", error);
     return { ok: false };
   }
-}
\ No newline at end of file
+}
+
+export async function selectPackFile(): Promise<string | null> {
+  try {
+    const selected = await open({
+      directory: false,
+      multiple: false,
+      title: "Выберите архив локализации",
+      filters: [{ name: "Пакет локализации", extensions: ["zip", "gz", "zst"] }],
+    });
+    if (typeof selected === "string") {
+      return selected;
+    }
+  } catch (error) {
+    console.error(error);
+  }
+  return null;
+}
+
+export async function installPackFromFile(path: string): Promise<LocalPackSummary | null> {
+  const summary = await invoke<LocalPackSummary>("inspect_local_pack", { path });
+  const confirmed = await ask(formatPackSummary(summary), {
+    title: "Установка локализации из файла",
+    kind: summary.compatibility?.compatibility === "incompatible" ? "warning" : "info",
+  });
+  if (!confirmed) {
+    return null;
+  }
+
+  await invoke<LocalizationUpdateOutcome>("install_local_pack", { path });
+  return summary;
+}
+
+function formatPackSummary(summary: LocalPackSummary): string {
+  const lines = [
+    `${summary.name ?? "Локализация"} v${summary.version}`,
+    `Текущая версия: ${summary.current_version ? `v${summary.current_version}` : "нет"}`,
+  ];
+  if (summary.authors.length > 0) {
+    lines.push(`Авторы: ${summary.authors.join(", ")}`);
+  }
+  if (summary.description) {
+    lines.push(summary.description);
+  }
+  lines.push(`Файлов шрифтов: ${summary.font_files}, файлов перевода: ${summary.language_files}`);
+
+  const report = summary.compatibility;
+  if (report?.compatibility === "incompatible") {
+    lines.push(`Пакет рассчитан на сборки ${report.supported_builds}, установлена ${report.game_build ?? "неизвестная"}`);
+  }
+  return lines.join("\n");
+}
+
+export async function buildLocalizationPack(options: PackBuildOptions): Promise<PackBuildResult> {
+  return invoke<PackBuildResult>("build_localization_pack", { options });
+}
+
+export async function getTranslationCoverage(): Promise<CoverageReport> {
+  return invoke<CoverageReport>("get_translation_coverage");
+}
+
+export async function exportTranslationCoverage(format: "json" | "markdown"): Promise<string> {
+  return invoke<string>("export_translation_coverage", { format });
+}
+
+export async function validateTranslation(): Promise<KeyProblem[]> {
+  return invoke<KeyProblem[]>("validate_translation");
+}
+
+export async function lintTranslation(path: string, fix: boolean): Promise<LintReport> {
+  return invoke<LintReport>("lint_translation", { path, fix });
+}
+
+export async function checkTypography(path: string, rewrite: boolean): Promise<TypographyChange[]> {
+  return invoke<TypographyChange[]>("check_typography", { path, rewrite });
+}
+
+export async function checkGlossary(path: string, glossary: string | null = null): Promise<GlossaryIssue[]> {
+  return invoke<GlossaryIssue[]>("check_glossary", { path, glossary });
+}
+
+export async function exportTranslation(path: string, format: ExchangeFormat, out: string): Promise<number> {
+  return invoke<number>("export_translation", { path, format, out });
+}
+
+export async function importTranslation(
+  path: string,
+  input: string,
+  format: ExchangeFormat | null = null,
+): Promise<ImportReport> {
+  return invoke<ImportReport>("import_translation", { path, input, format });
+}
+
+export async function checkStaleTranslations(path: string): Promise<StaleReport> {
+  return invoke<StaleReport>("check_stale_translations", { path });
+}
+
+export async function recordSourceHashes(path: string, accept: string[] = []): Promise<StaleReport> {
+  return invoke<StaleReport>("record_source_hashes", { path, accept });
+}
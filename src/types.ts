@@ -9,4 +9,221 @@ export interface LocalizationUpdateInfo {
 	update_available: boolean;
 	download_url: string | null;
 	changelog: string | null;
-}
\ No newline at end of file
+}
+
+export interface ProfileApplyResult {
+	profile: string;
+	game_path: string;
+	status: "applied" | "skipped" | "failed";
+	error: string | null;
+	warnings: string[];
+	problems: KeyProblem[];
+}
+
+export interface InstallReport {
+	warnings: string[];
+	problems: KeyProblem[];
+}
+
+export interface CompatibilityReport {
+	game_build: string | null;
+	pack_version: string;
+	supported_builds: string;
+	compatibility: "compatible" | "incompatible" | "unknown";
+	blocked: boolean;
+}
+
+export interface LocalPackSummary {
+	name: string | null;
+	version: string;
+	description: string | null;
+	authors: string[];
+	provider: string;
+	current_version: string | null;
+	font_files: number;
+	language_files: number;
+	compatibility: CompatibilityReport | null;
+}
+
+export interface DeltaStatus {
+	applied: boolean;
+	downloaded: number;
+	total: number;
+	error: string | null;
+}
+
+export interface LocalizationUpdateOutcome {
+	updated: boolean;
+	profiles: ProfileApplyResult[];
+	delta: DeltaStatus | null;
+	warnings: string[];
+	problems: KeyProblem[];
+}
+
+export interface TranslationProvider {
+	id: string;
+	name: string;
+	repository: string;
+	asset_pattern: string;
+	authors: string[];
+	homepage: string | null;
+	builtin: boolean;
+	installed_version: string | null;
+	active: boolean;
+}
+
+export interface PackBuildOptions {
+	source_dir: string;
+	output_dir?: string | null;
+	name?: string | null;
+	signing_key?: string | null;
+	base_url?: string | null;
+}
+
+export interface PackBuildResult {
+	version: string;
+	archive: string;
+	sha256: string;
+	hash_file: string;
+	signature_file: string | null;
+	public_key: string | null;
+	index_file: string | null;
+	files: number;
+}
+
+export interface AvailablePack {
+	id: string;
+	provider: string;
+	version: string;
+	disabled_by_default: boolean;
+	enabled: boolean;
+}
+
+export interface PlannedPack {
+	id: string;
+	provider: string;
+	version: string;
+	optional: boolean;
+}
+
+export interface DependencyPlan {
+	order: PlannedPack[];
+	conflicts: string[];
+	skipped: string[];
+}
+
+export interface TranslationLayer {
+	name: string;
+	path: string;
+	priority: number;
+	enabled: boolean;
+}
+
+export interface KeyOrigin {
+	file: string;
+	key: string;
+	layer: string;
+	value: string;
+	overridden: string[];
+}
+
+export interface FileCoverage {
+	file: string;
+	total: number;
+	translated: number;
+	percent: number;
+	missing: string[];
+	obsolete: string[];
+}
+
+export interface CoverageReport {
+	game_build: string | null;
+	total: number;
+	translated: number;
+	percent: number;
+	files: FileCoverage[];
+}
+
+export type ProblemKind = "syntax" | "placeholders" | "linebreaks" | "markup";
+
+export interface KeyProblem {
+	file: string;
+	key: string;
+	kind: ProblemKind;
+	message: string;
+}
+
+export type LintSeverity = "off" | "info" | "warning" | "error";
+
+export interface LintIssue {
+	file: string;
+	line: number;
+	key: string | null;
+	rule: string;
+	severity: LintSeverity;
+	message: string;
+	fixed: boolean;
+}
+
+export interface LintReport {
+	issues: LintIssue[];
+	errors: number;
+	warnings: number;
+	fixed: number;
+}
+
+export interface TypographyChange {
+	file: string;
+	key: string;
+	line: number;
+	before: string;
+	after: string;
+}
+
+export type GlossaryIssueKind = "missing" | "forbidden";
+
+export interface GlossaryIssue {
+	file: string;
+	key: string;
+	line: number;
+	term: string;
+	kind: GlossaryIssueKind;
+	message: string;
+}
+
+export type ExchangeFormat = "po" | "xliff" | "csv";
+
+export type ImportConflictKind = "duplicate" | "unknown-key" | "source-changed";
+
+export interface ImportConflict {
+	file: string;
+	key: string;
+	kind: ImportConflictKind;
+	message: string;
+}
+
+export interface ImportReport {
+	encoding: string;
+	updated: number;
+	unchanged: number;
+	untranslated: number;
+	files: string[];
+	conflicts: ImportConflict[];
+}
+
+export interface StaleEntry {
+	file: string;
+	key: string;
+	line: number;
+	old: string | null;
+	new: string;
+	diff: string;
+	since_build: string | null;
+}
+
+export interface StaleReport {
+	game_build: string | null;
+	tracked: number;
+	untracked: number;
+	stale: StaleEntry[];
+}